@@ -71,6 +71,7 @@ pub mod timetraveler_timeline {
     }
 
     /// Update mid-prediction stats (pool tokens / followers).
+    /// Callable by the archive authority or the archive writer.
     pub fn update_prediction_stats(
         ctx: Context<UpdateStats>,
         total_pool_tokens: u64,
@@ -78,7 +79,7 @@ pub mod timetraveler_timeline {
     ) -> Result<()> {
         let rec = &mut ctx.accounts.prediction;
 
-        // Authority gate enforced by account validation
+        // Authority / writer gate enforced by account validation
         rec.total_pool_tokens = total_pool_tokens;
         rec.followers = followers;
 
@@ -87,6 +88,7 @@ pub mod timetraveler_timeline {
 
     /// Resolve prediction with final outcome and payout ratio.
     /// payout_ratio_bps is in basis points (e.g. 10000 = 100%, 2500 = 25%)
+    /// Callable by the archive authority or the archive writer.
     pub fn resolve_prediction(
         ctx: Context<ResolvePrediction>,
         outcome: Outcome,
@@ -115,6 +117,24 @@ pub mod timetraveler_timeline {
         archive.authority = new_authority;
         Ok(())
     }
+
+    /// Authorize a second key (e.g. the pool program's registry PDA) to update
+    /// stats and resolve predictions. Kept in its own PDA so the Archive
+    /// layout of existing deployments is unchanged.
+    pub fn init_archive_writer(ctx: Context<InitArchiveWriter>, writer: Pubkey) -> Result<()> {
+        let archive_writer = &mut ctx.accounts.archive_writer;
+        archive_writer.archive = ctx.accounts.archive.key();
+        archive_writer.writer = writer;
+        archive_writer.bump = *ctx.bumps.get("archive_writer").unwrap();
+        Ok(())
+    }
+
+    /// Rotate the archive writer. Pubkey::default() revokes it.
+    pub fn set_archive_writer(ctx: Context<SetArchiveWriter>, writer: Pubkey) -> Result<()> {
+        let archive_writer = &mut ctx.accounts.archive_writer;
+        archive_writer.writer = writer;
+        Ok(())
+    }
 }
 
 /* ----------------------------
@@ -176,7 +196,7 @@ pub struct UpdateStats<'info> {
     #[account(
         seeds = [b"archive"],
         bump = archive.bump,
-        has_one = authority
+        constraint = archive.is_writer(archive_writer.as_deref(), authority.key) @ TimelineError::Unauthorized
     )]
     pub archive: Account<'info, Archive>,
 
@@ -191,8 +211,15 @@ pub struct UpdateStats<'info> {
     )]
     pub prediction: Account<'info, PredictionRecord>,
 
-    #[account(mut)]
+    /// Archive authority or writer (may be a PDA signing through CPI)
     pub authority: Signer<'info>,
+
+    /// Writer PDA; only needed when the signer is not the archive authority
+    #[account(
+        seeds = [b"writer", archive.key().as_ref()],
+        bump = archive_writer.bump
+    )]
+    pub archive_writer: Option<Account<'info, ArchiveWriter>>,
 }
 
 #[derive(Accounts)]
@@ -200,7 +227,7 @@ pub struct ResolvePrediction<'info> {
     #[account(
         seeds = [b"archive"],
         bump = archive.bump,
-        has_one = authority
+        constraint = archive.is_writer(archive_writer.as_deref(), authority.key) @ TimelineError::Unauthorized
     )]
     pub archive: Account<'info, Archive>,
 
@@ -215,8 +242,15 @@ pub struct ResolvePrediction<'info> {
     )]
     pub prediction: Account<'info, PredictionRecord>,
 
-    #[account(mut)]
+    /// Archive authority or writer (may be a PDA signing through CPI)
     pub authority: Signer<'info>,
+
+    /// Writer PDA; only needed when the signer is not the archive authority
+    #[account(
+        seeds = [b"writer", archive.key().as_ref()],
+        bump = archive_writer.bump
+    )]
+    pub archive_writer: Option<Account<'info, ArchiveWriter>>,
 }
 
 #[derive(Accounts)]
@@ -233,6 +267,50 @@ pub struct SetArchiveAuthority<'info> {
     pub authority: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct InitArchiveWriter<'info> {
+    #[account(
+        seeds = [b"archive"],
+        bump = archive.bump,
+        has_one = authority
+    )]
+    pub archive: Account<'info, Archive>,
+
+    /// PDA for the archive writer: seeds = ["writer", archive]
+    #[account(
+        init,
+        payer = authority,
+        space = ArchiveWriter::SPACE,
+        seeds = [b"writer", archive.key().as_ref()],
+        bump
+    )]
+    pub archive_writer: Account<'info, ArchiveWriter>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetArchiveWriter<'info> {
+    #[account(
+        seeds = [b"archive"],
+        bump = archive.bump,
+        has_one = authority
+    )]
+    pub archive: Account<'info, Archive>,
+
+    #[account(
+        mut,
+        seeds = [b"writer", archive.key().as_ref()],
+        bump = archive_writer.bump
+    )]
+    pub archive_writer: Account<'info, ArchiveWriter>,
+
+    pub authority: Signer<'info>,
+}
+
 /* ----------------------------
    State
 ----------------------------- */
@@ -247,6 +325,26 @@ pub struct Archive {
 impl Archive {
     // Discriminator (8) + Pubkey(32) + bump(1) + u64(8) + padding
     pub const SPACE: usize = 8 + 32 + 1 + 8;
+
+    /// Whether `key` may update stats and resolve predictions: the archive
+    /// authority, or the writer recorded in the archive's writer PDA.
+    pub fn is_writer(&self, archive_writer: Option<&ArchiveWriter>, key: &Pubkey) -> bool {
+        *key == self.authority
+            || matches!(archive_writer, Some(w) if w.writer != Pubkey::default() && *key == w.writer)
+    }
+}
+
+/// Extra key allowed to update/resolve predictions (seeds = ["writer", archive])
+#[account]
+pub struct ArchiveWriter {
+    pub archive: Pubkey, // link to Archive PDA
+    pub writer: Pubkey,  // default = none
+    pub bump: u8,
+}
+
+impl ArchiveWriter {
+    // Discriminator (8) + Pubkey(32) + Pubkey(32) + bump(1)
+    pub const SPACE: usize = 8 + 32 + 32 + 1;
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
@@ -269,7 +367,7 @@ pub struct PredictionRecord {
     pub total_pool_tokens: u64,   // SPL base units
     pub followers: u64,
     pub outcome: Outcome,         // 1 byte via enum repr (Anchor serializes)
-    pub payout_ratio_bps: u16,    // 0..=10000
+    pub payout_ratio_bps: u16,    // payout per unit staked, 10000 = 1x
     pub maturity_timestamp: i64,  // when resolved
     pub ipfs_hash: String,        // max 64 (4 + 64)
     pub bump: u8,
@@ -335,4 +433,6 @@ pub enum TimelineError {
     AlreadyResolved,
     #[msg("Math overflow")]
     MathOverflow,
+    #[msg("Signer is not the archive authority or writer")]
+    Unauthorized,
 }
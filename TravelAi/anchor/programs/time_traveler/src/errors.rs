@@ -1 +1,136 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum TimeTravelerError {
+    #[msg("Signer is not authorized for this action")]
+    Unauthorized,
+    #[msg("New authority must differ from the current one")]
+    NoAuthorityChange,
+    #[msg("No authority handover is pending")]
+    NoPendingAuthority,
+    #[msg("Invalid configuration parameters")]
+    InvalidConfig,
+    #[msg("Pool does not belong to this registry")]
+    InvalidRegistryReference,
+    #[msg("Token mint does not match the pool mint")]
+    InvalidMint,
+    #[msg("Vault is not owned by the pool")]
+    InvalidVaultOwner,
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+    #[msg("open_ts must be before close_ts")]
+    InvalidTimestamps,
+    #[msg("close_ts must be in the future")]
+    PoolCloseInPast,
+    #[msg("Pool has not opened yet")]
+    PoolNotOpenYet,
+    #[msg("Pool is locked ahead of close")]
+    PoolLocked,
+    #[msg("Pool is closed to contributions")]
+    PoolClosed,
+    #[msg("Pool has already been resolved or cancelled")]
+    PoolAlreadyClosed,
+    #[msg("Pool is still active")]
+    PoolStillActive,
+    #[msg("Pool has not been resolved yet")]
+    PoolNotClosed,
+    #[msg("Contribution did not back the winning outcome")]
+    NotAWinner,
+    #[msg("Pool has not been cancelled")]
+    PoolNotCancelled,
+    #[msg("Winnings already claimed")]
+    AlreadyClaimed,
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+    #[msg("Contribution already backs a different outcome")]
+    OutcomeMismatch,
+    #[msg("Pool must declare between 2 and MAX_OUTCOMES outcomes")]
+    InvalidOutcomeCount,
+    #[msg("Outcome index is out of range for this pool")]
+    InvalidOutcome,
+    #[msg("Lower bound must be below upper bound")]
+    InvalidBounds,
+    #[msg("Resolution type does not match the pool kind")]
+    ResolutionKindMismatch,
+    #[msg("Contribution does not belong to this pool")]
+    InvalidContribution,
+    #[msg("Resolution does not match the pool's resolution source")]
+    ResolutionSourceMismatch,
+    #[msg("No oracle feed is configured on the registry")]
+    OracleNotConfigured,
+    #[msg("Oracle feed account is required for this pool")]
+    OracleFeedMissing,
+    #[msg("Oracle account is not the configured price feed")]
+    InvalidOracleAccount,
+    #[msg("Oracle price is not currently available")]
+    OraclePriceUnavailable,
+    #[msg("Oracle price is too old relative to pool close")]
+    StaleOraclePrice,
+    #[msg("Oracle price was published too long after pool close")]
+    OraclePriceTooLate,
+    #[msg("Oracle confidence interval is too wide")]
+    OracleConfidenceTooWide,
+    #[msg("Oracle feeds disagree beyond the allowed deviation")]
+    OracleDeviationTooLarge,
+    #[msg("Referral account does not match the referrer")]
+    InvalidReferrer,
+    #[msg("Contributors cannot refer themselves")]
+    SelfReferral,
+    #[msg("Pool has no pending proposal")]
+    PoolNotProposed,
+    #[msg("Pool is not under dispute")]
+    PoolNotDisputed,
+    #[msg("Dispute window is still open")]
+    DisputeWindowOpen,
+    #[msg("Dispute window has closed")]
+    DisputeWindowClosed,
+    #[msg("No arbiter is configured on the registry")]
+    ArbiterNotConfigured,
+    #[msg("Arbitration deadline has not passed")]
+    ArbitrationWindowOpen,
+    #[msg("Arbitration deadline has passed")]
+    ArbitrationWindowClosed,
+    #[msg("Role table has no free slot")]
+    RoleTableFull,
+    #[msg("Key does not hold this role")]
+    RoleNotHeld,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Pool is paused")]
+    PoolPaused,
+    #[msg("Early exits are closed this close to pool close")]
+    ExitLockedOut,
+    #[msg("Contribution is below the pool minimum")]
+    ContributionTooSmall,
+    #[msg("Contribution exceeds the per-wallet cap for this pool")]
+    WalletCapExceeded,
+    #[msg("Contribution exceeds the pool hard cap")]
+    PoolCapReached,
+    #[msg("Token account is required for this pool")]
+    TokenAccountMissing,
+    #[msg("Pool is not a community pool")]
+    NotCommunityPool,
+    #[msg("Pool metadata field exceeds its maximum length")]
+    MetadataTooLong,
+    #[msg("Timeline accounts are required to close this pool")]
+    TimelineAccountsMissing,
+    #[msg("Timeline account does not match the registry configuration")]
+    InvalidTimelineAccount,
+    #[msg("Only pools created by a PoolCreator can link a timeline prediction")]
+    TimelineLinkNotAllowed,
+    #[msg("Position is still owed a payout or refund")]
+    PositionNotFinal,
+    #[msg("Pool is not settled")]
+    PoolNotSettled,
+    #[msg("Pool still has contributions or a creation bond outstanding")]
+    PoolAccountsOutstanding,
+    #[msg("Pool close grace period has not elapsed")]
+    CloseGracePeriodActive,
+    #[msg("Withdrawal would use referral rewards still owed")]
+    TreasuryReserved,
+    #[msg("Pool account is not at the address its seeds derive")]
+    InvalidPoolAddress,
+    #[msg("Numerical overflow")]
+    NumericalOverflow,
+}
 // Custom errors
@@ -1 +1,280 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{OracleType, PoolKind, PoolStatus, Role};
+
+/// Event emitted when the registry is initialized
+#[event]
+pub struct RegistryInitialized {
+    pub registry: Pubkey,
+    pub authority: Pubkey,
+}
+
+/// Event emitted when a new registry authority is nominated
+#[event]
+pub struct AuthorityProposed {
+    pub registry: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+/// Event emitted when a pending authority nomination is withdrawn
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub registry: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+/// Event emitted when the registry authority changes
+#[event]
+pub struct AuthorityChanged {
+    pub registry: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+/// Event emitted when registry config is updated
+#[event]
+pub struct ConfigUpdated {
+    pub registry: Pubkey,
+    pub fee_bps: u16,
+    pub oracle: Pubkey,
+    pub oracle_type: OracleType,
+    pub secondary_oracle: Pubkey,
+    pub config_version: u64,
+}
+
+/// Event emitted when a pool is created
+#[event]
+pub struct PoolCreated {
+    pub pool: Pubkey,
+    pub signal_id: [u8; 32],
+    pub mint: Pubkey,
+    pub open_ts: i64,
+    pub close_ts: i64,
+    pub num_outcomes: u8,
+    pub kind: PoolKind,
+}
+
+/// Event emitted on every contribution
+#[event]
+pub struct Contributed {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub outcome: u8,
+    pub amount: u64,
+    pub total_contributed: u64,
+}
+
+/// Event emitted when a pool is resolved
+#[event]
+pub struct PoolClosed {
+    pub pool: Pubkey,
+    pub signal_id: [u8; 32],
+    pub winning_outcome: Option<u8>,
+    pub resolved_value: Option<i64>,
+    pub total_contributed: u64,
+    pub refunded: bool, // no stake on the result; cancelled so every position is refunded
+}
+
+/// Event emitted when a closed pool's timeline record could not be updated
+/// (already resolved, missing, or the registry is not its archive writer)
+#[event]
+pub struct TimelineMirrorSkipped {
+    pub pool: Pubkey,
+    pub prediction_id: u64,
+}
+
+/// Event emitted when a pool moves between clock-driven phases
+#[event]
+pub struct PoolStatusSynced {
+    pub pool: Pubkey,
+    pub status: PoolStatus,
+}
+
+/// Event emitted once every payout or refund of a pool has been made
+#[event]
+pub struct PoolSettled {
+    pub pool: Pubkey,
+}
+
+/// Event emitted when a contributor claims winnings
+#[event]
+pub struct WinningsClaimed {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub payout: u64,
+    pub fee: u64,
+    pub creator_fee: u64,
+}
+
+/// Event emitted when the authority cancels a pool
+#[event]
+pub struct PoolCancelled {
+    pub pool: Pubkey,
+    pub signal_id: [u8; 32],
+    pub total_contributed: u64,
+    pub invalid: bool,
+}
+
+/// Event emitted when a contributor is refunded from a cancelled pool
+#[event]
+pub struct Refunded {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+/// Event emitted when protocol fees leave a treasury
+#[event]
+pub struct FeesWithdrawn {
+    pub registry: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub fees_withdrawn: u64,
+}
+
+/// Event emitted when a claim credits a referrer with part of its fee
+#[event]
+pub struct ReferralCredited {
+    pub referrer: Pubkey,
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub volume: u64,
+    pub reward: u64,
+}
+
+/// Event emitted when a referrer claims rewards
+#[event]
+pub struct ReferralRewardsClaimed {
+    pub referrer: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Event emitted when an outcome is proposed and the dispute window opens
+#[event]
+pub struct OutcomeProposed {
+    pub pool: Pubkey,
+    pub winning_outcome: Option<u8>,
+    pub resolved_value: Option<i64>,
+    pub dispute_deadline: i64,
+}
+
+/// Event emitted when a proposed outcome is disputed
+#[event]
+pub struct OutcomeDisputed {
+    pub pool: Pubkey,
+    pub disputer: Pubkey,
+    pub bond: u64,
+    pub arbitration_deadline: i64,
+}
+
+/// Event emitted when the arbiter settles a dispute
+#[event]
+pub struct DisputeResolved {
+    pub pool: Pubkey,
+    pub disputer: Pubkey,
+    pub proposal_upheld: bool, // true = bond slashed, false = bond refunded
+    pub bond: u64,
+}
+
+/// Event emitted when a dispute outlives the arbitration deadline
+#[event]
+pub struct DisputeExpired {
+    pub pool: Pubkey,
+    pub disputer: Pubkey,
+    pub bond: u64, // refunded to the disputer
+}
+
+/// Event emitted when a role is granted
+#[event]
+pub struct RoleGranted {
+    pub registry: Pubkey,
+    pub member: Pubkey,
+    pub role: Role,
+}
+
+/// Event emitted when a role is revoked
+#[event]
+pub struct RoleRevoked {
+    pub registry: Pubkey,
+    pub member: Pubkey,
+    pub role: Role,
+}
+
+/// Event emitted when the program-wide pause switch is toggled
+#[event]
+pub struct RegistryPauseSet {
+    pub registry: Pubkey,
+    pub pauser: Pubkey,
+    pub paused: bool,
+    pub reason: u16, // operator-defined reason code for monitoring
+}
+
+/// Event emitted when a single pool is paused or resumed
+#[event]
+pub struct PoolPauseSet {
+    pub pool: Pubkey,
+    pub pauser: Pubkey,
+    pub paused: bool,
+    pub reason: u16, // operator-defined reason code for monitoring
+}
+
+/// Event emitted when a contributor exits early
+#[event]
+pub struct ContributionWithdrawn {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub outcome: u8,
+    pub amount: u64,  // returned to the contributor
+    pub penalty: u64, // kept in the pool
+    pub total_contributed: u64,
+}
+
+/// Event emitted when a community pool is created
+#[event]
+pub struct CommunityPoolCreated {
+    pub pool: Pubkey,
+    pub creator: Pubkey,
+    pub creation_bond: u64,
+    pub creator_fee_bps: u16,
+}
+
+/// Event emitted when a community pool's creation bond is returned or slashed
+#[event]
+pub struct CreationBondReleased {
+    pub pool: Pubkey,
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub slashed: bool,
+}
+
+/// Event emitted when a community pool creator collects fees
+#[event]
+pub struct CreatorFeesClaimed {
+    pub pool: Pubkey,
+    pub creator: Pubkey,
+    pub amount: u64,
+}
+/// Event emitted when a pool's metadata is updated
+#[event]
+pub struct PoolMetadataUpdated {
+    pub pool: Pubkey,
+    pub authority: Pubkey,
+}
+/// Event emitted when a Contribution account is closed
+#[event]
+pub struct ContributionClosed {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+}
+
+/// Event emitted when a settled pool, its vault and metadata are closed
+#[event]
+pub struct PoolAccountsClosed {
+    pub pool: Pubkey,
+    pub dust: u64, // leftover vault balance sent to the treasury
+}
 // Events
@@ -1 +0,0 @@
-// Instructions mod
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Complete an authority handover; must be signed by the pending authority.
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    require!(
+        registry.pending_authority != Pubkey::default(),
+        TimeTravelerError::NoPendingAuthority
+    );
+
+    let old_authority = registry.authority;
+    registry.authority = registry.pending_authority;
+    registry.pending_authority = Pubkey::default();
+
+    emit!(AuthorityChanged {
+        registry: registry.key(),
+        old_authority,
+        new_authority: registry.authority,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    /// The nominated authority
+    pub pending_authority: Signer<'info>,
+
+    /// Global registry (pending authority must match signer)
+    #[account(
+        mut,
+        has_one = pending_authority @ TimeTravelerError::Unauthorized
+    )]
+    pub registry: Account<'info, Registry>,
+}
+// accept_authority instruction
@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Settle a disputed proposal and close the pool on the arbiter's resolution.
+/// If the resolution matches the proposal the dispute bond is slashed to the
+/// registry treasury; otherwise the proposal is overturned and the bond is
+/// refunded to the disputer. Categorical and range pools match on the winning
+/// bucket; scalar pools, which pay by the value, only on the exact value.
+/// Must land before the arbitration deadline.
+/// Pools linked to a timeline prediction mirror the result into it through
+/// `mirror_pool_result`.
+/// Only callable by the registry arbiter.
+pub fn handler(ctx: Context<Arbitrate>, resolution: Resolution) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    require!(
+        pool.status == PoolStatus::Disputed,
+        TimeTravelerError::PoolNotDisputed
+    );
+    require!(
+        Clock::get()?.unix_timestamp < pool.dispute_deadline,
+        TimeTravelerError::ArbitrationWindowClosed
+    );
+
+    let (proposed_outcome, proposed_value) = (pool.winning_outcome, pool.resolved_value);
+    pool.apply_resolution(resolution)?;
+    let proposal_upheld = pool.same_result(proposed_outcome, proposed_value);
+
+    // Move the bond out of the vault, signed by pool PDA
+    let bond = pool.dispute_bond;
+    if bond > 0 {
+        let balance_before = ctx.accounts.treasury.amount;
+        let destination = if proposal_upheld {
+            ctx.accounts.treasury.to_account_info()
+        } else {
+            ctx.accounts.disputer_token_account.to_account_info()
+        };
+        let pool_seeds = pool.signer_seeds();
+        let signer_seeds: &[&[&[u8]]] = &[&pool_seeds];
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.pool_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: destination,
+            authority: pool.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_interface::transfer_checked(cpi_ctx, bond, ctx.accounts.mint.decimals)?;
+
+        if proposal_upheld {
+            // Count what arrived, net of any mint transfer fee
+            ctx.accounts.treasury.reload()?;
+            let received = safe_sub(ctx.accounts.treasury.amount, balance_before)?;
+            let stats = &mut ctx.accounts.treasury_stats;
+            stats.fees_collected = safe_add(stats.fees_collected, received)?;
+        }
+    }
+
+    pool.dispute_bond = 0;
+    pool.mark_resolved()?;
+
+    // Mirror the result into the timeline archive
+    mirror_pool_result(
+        pool,
+        &ctx.accounts.registry,
+        ctx.accounts.timeline_program.as_ref(),
+        ctx.accounts.timeline_archive.as_ref(),
+        ctx.accounts.timeline_writer.as_ref(),
+        ctx.accounts.timeline_prediction.as_ref(),
+    )?;
+
+    emit!(DisputeResolved {
+        pool: pool.key(),
+        disputer: pool.disputer,
+        proposal_upheld,
+        bond,
+    });
+
+    emit!(PoolClosed {
+        pool: pool.key(),
+        signal_id: pool.signal_id,
+        winning_outcome: pool.winning_outcome,
+        resolved_value: pool.resolved_value,
+        total_contributed: pool.total_contributed,
+        refunded: pool.status != PoolStatus::Resolved,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Arbitrate<'info> {
+    /// Arbiter settling the dispute (must match registry arbiter)
+    pub arbiter: Signer<'info>,
+
+    /// Global registry (arbiter)
+    #[account(
+        has_one = arbiter @ TimeTravelerError::Unauthorized,
+        constraint = !registry.paused @ TimeTravelerError::ProgramPaused
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// The disputed pool
+    #[account(
+        mut,
+        has_one = registry @ TimeTravelerError::InvalidRegistryReference,
+        constraint = !pool.paused @ TimeTravelerError::PoolPaused,
+        constraint = pool.is_pda(&pool.key()) @ TimeTravelerError::InvalidPoolAddress
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Pool vault holding the dispute bond
+    #[account(
+        mut,
+        seeds = [b"vault", pool.key().as_ref()],
+        bump,
+        constraint = pool_vault.owner == pool.key() @ TimeTravelerError::InvalidVaultOwner,
+        constraint = pool_vault.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Disputer’s token account, receives the bond if the proposal is overturned
+    #[account(
+        mut,
+        constraint = disputer_token_account.owner == pool.disputer @ TimeTravelerError::Unauthorized,
+        constraint = disputer_token_account.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub disputer_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Registry treasury for the pool mint, receives a slashed bond
+    #[account(
+        mut,
+        seeds = [b"treasury", registry.key().as_ref(), pool.mint.as_ref()],
+        bump
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    /// Fee totals for the treasury
+    #[account(
+        mut,
+        seeds = [b"treasury_stats", registry.key().as_ref(), pool.mint.as_ref()],
+        bump = treasury_stats.bump
+    )]
+    pub treasury_stats: Account<'info, TreasuryStats>,
+
+    /// Mint of the pool, needed for checked transfers
+    #[account(address = pool.mint @ TimeTravelerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Timeline program configured on the registry; only required for
+    /// pools linked to a timeline prediction.
+    #[account(
+        executable,
+        constraint = timeline_program.key() == registry.timeline_program @ TimeTravelerError::InvalidTimelineAccount
+    )]
+    pub timeline_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Timeline Archive PDA; address checked in `mirror_pool_result`
+    /// and validated by the timeline program.
+    pub timeline_archive: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Timeline ArchiveWriter PDA naming the registry PDA as writer;
+    /// address checked in `mirror_pool_result` and validated by the timeline program.
+    pub timeline_writer: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Timeline PredictionRecord PDA for `pool.timeline_prediction_id`;
+    /// address checked in `mirror_pool_result` and validated by the timeline program.
+    #[account(mut)]
+    pub timeline_prediction: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+// arbitrate instruction
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Withdraw a pending authority nomination before it is accepted.
+pub fn handler(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    require!(
+        registry.pending_authority != Pubkey::default(),
+        TimeTravelerError::NoPendingAuthority
+    );
+
+    let pending_authority = registry.pending_authority;
+    registry.pending_authority = Pubkey::default();
+
+    emit!(AuthorityTransferCancelled {
+        registry: registry.key(),
+        authority: registry.authority,
+        pending_authority,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    /// The current authority of the registry
+    pub authority: Signer<'info>,
+
+    /// Global registry (authority must match signer)
+    #[account(
+        mut,
+        has_one = authority @ TimeTravelerError::Unauthorized
+    )]
+    pub registry: Account<'info, Registry>,
+}
+// cancel_authority_transfer instruction
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Void a pool that has not been resolved yet.
+/// Contributions stay in the vault and are returned through `refund`.
+/// `invalid` marks a malformed pool; a community pool's creation bond is
+/// then slashed instead of returned.
+/// Callable by the registry authority or a Resolver.
+pub fn handler(ctx: Context<CancelPool>, invalid: bool) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    require!(pool.is_unresolved(), TimeTravelerError::PoolAlreadyClosed);
+
+    pool.invalid = invalid;
+    pool.mark_cancelled()?;
+
+    emit!(PoolCancelled {
+        pool: pool.key(),
+        signal_id: pool.signal_id,
+        total_contributed: pool.total_contributed,
+        invalid,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelPool<'info> {
+    /// Registry authority or a Resolver cancelling the pool
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = registry.has_role(authority.key, roles.as_deref(), Role::Resolver) @ TimeTravelerError::Unauthorized
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// Role table; only needed when the signer is not the registry authority
+    #[account(
+        seeds = [b"roles", registry.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Option<Account<'info, Roles>>,
+
+    /// The pool being cancelled
+    #[account(
+        mut,
+        has_one = registry @ TimeTravelerError::InvalidRegistryReference
+    )]
+    pub pool: Account<'info, Pool>,
+}
+// cancel_pool instruction
@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Claim winnings from a resolved pool.
+/// Contributors who backed the winning outcome receive their pro-rata share of
+/// the whole pool (parimutuel across all buckets) minus the registry protocol
+/// fee; on scalar pools both sides are paid by where the value landed.
+/// The fee is moved to the registry treasury for the pool mint; a referred
+/// position credits `registry.referral_bps` of the fee received to its referrer
+/// here rather than in `contribute`, as no fee exists before the claim.
+/// Community pools also set aside the creator fee in the vault.
+/// Native-SOL pools pay the claimant in lamports.
+pub fn handler(ctx: Context<Claim>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let registry = &ctx.accounts.registry;
+    let contrib = &mut ctx.accounts.contribution;
+
+    require!(
+        pool.status == PoolStatus::Resolved,
+        TimeTravelerError::PoolNotClosed
+    );
+    require!(!contrib.claimed, TimeTravelerError::AlreadyClaimed);
+    require!(contrib.amount > 0, TimeTravelerError::NothingToClaim);
+
+    // Losing buckets' stake is shared among winners pro-rata to their stake
+    require!(pool.outcome_pays(contrib.outcome)?, TimeTravelerError::NotAWinner);
+    let share = pool.payout_for(contrib.outcome, contrib.amount)?;
+    let (payout, fee) = split_fee(share, registry.fee_bps)?;
+    // Community pool creators take their cut from the winner's side
+    let creator_fee = apply_bps(share, pool.creator_fee_bps, Rounding::Down)?;
+    let payout = safe_sub(payout, creator_fee)?;
+    pool.creator_fees = safe_add(pool.creator_fees, creator_fee)?;
+
+    // Mark claimed before moving funds
+    contrib.claimed = true;
+    let settled = pool.settle_position(contrib.amount)?;
+
+    // Transfer payout from pool vault -> claimant, signed by pool PDA
+    let pool_seeds = pool.signer_seeds();
+    let signer_seeds: &[&[&[u8]]] = &[&pool_seeds];
+    if payout > 0 {
+        if pool.native_sol {
+            // Unwrap to lamports through a temporary wSOL account
+            let unwrap_account = ctx
+                .accounts
+                .unwrap_account
+                .as_ref()
+                .ok_or(TimeTravelerError::TokenAccountMissing)?;
+            unwrap_sol(
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.pool_vault.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                unwrap_account.to_account_info(),
+                pool.to_account_info(),
+                ctx.accounts.claimant.to_account_info(),
+                signer_seeds,
+                payout,
+                ctx.accounts.mint.decimals,
+            )?;
+        } else {
+            let user_token_account = ctx
+                .accounts
+                .user_token_account
+                .as_ref()
+                .ok_or(TimeTravelerError::TokenAccountMissing)?;
+            let cpi_accounts = TransferChecked {
+                from: ctx.accounts.pool_vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: user_token_account.to_account_info(),
+                authority: pool.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token_interface::transfer_checked(cpi_ctx, payout, ctx.accounts.mint.decimals)?;
+        }
+    }
+
+    // Transfer protocol fee from pool vault -> treasury
+    if fee > 0 {
+        let balance_before = ctx.accounts.treasury.amount;
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.pool_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.treasury.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_interface::transfer_checked(cpi_ctx, fee, ctx.accounts.mint.decimals)?;
+
+        // Count what arrived, net of any mint transfer fee
+        ctx.accounts.treasury.reload()?;
+        let received = safe_sub(ctx.accounts.treasury.amount, balance_before)?;
+        let stats = &mut ctx.accounts.treasury_stats;
+        stats.fees_collected = safe_add(stats.fees_collected, received)?;
+
+        // Credit the referrer with its share of the fee just collected
+        if contrib.referral != Pubkey::default() {
+            let referral = ctx
+                .accounts
+                .referral
+                .as_mut()
+                .ok_or(TimeTravelerError::InvalidReferrer)?;
+            let reward = apply_bps(received, registry.referral_bps, Rounding::Down)?;
+            referral.rewards_accrued = safe_add(referral.rewards_accrued, reward)?;
+            stats.referral_rewards_accrued = safe_add(stats.referral_rewards_accrued, reward)?;
+
+            emit!(ReferralCredited {
+                referrer: referral.referrer,
+                pool: pool.key(),
+                user: contrib.user,
+                volume: contrib.amount,
+                reward,
+            });
+        }
+    }
+
+    emit!(WinningsClaimed {
+        pool: pool.key(),
+        user: contrib.user,
+        payout,
+        fee,
+        creator_fee,
+    });
+
+    if settled {
+        emit!(PoolSettled { pool: pool.key() });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    /// Contributor claiming their winnings
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// Global registry (fee configuration)
+    #[account(
+        constraint = !registry.paused @ TimeTravelerError::ProgramPaused
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// The resolved pool
+    #[account(
+        mut,
+        has_one = registry @ TimeTravelerError::InvalidRegistryReference,
+        constraint = !pool.paused @ TimeTravelerError::PoolPaused,
+        constraint = pool.is_pda(&pool.key()) @ TimeTravelerError::InvalidPoolAddress
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Claimant’s Contribution PDA
+    #[account(
+        mut,
+        seeds = [b"contrib", pool.key().as_ref(), claimant.key().as_ref()],
+        bump,
+        constraint = contribution.pool == pool.key() @ TimeTravelerError::InvalidContribution
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    /// Pool vault holding all contributions
+    #[account(
+        mut,
+        seeds = [b"vault", pool.key().as_ref()],
+        bump,
+        constraint = pool_vault.owner == pool.key() @ TimeTravelerError::InvalidVaultOwner,
+        constraint = pool_vault.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Claimant’s token account receiving the payout; not used by
+    /// native-SOL pools, which pay out lamports
+    #[account(
+        mut,
+        constraint = user_token_account.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub user_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Registry treasury for the pool mint, receives the protocol fee
+    #[account(
+        mut,
+        seeds = [b"treasury", registry.key().as_ref(), pool.mint.as_ref()],
+        bump
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    /// Fee totals for the treasury
+    #[account(
+        mut,
+        seeds = [b"treasury_stats", registry.key().as_ref(), pool.mint.as_ref()],
+        bump = treasury_stats.bump
+    )]
+    pub treasury_stats: Account<'info, TreasuryStats>,
+
+    /// Referral PDA the position was opened with, credited a share of the fee
+    #[account(
+        mut,
+        address = contribution.referral @ TimeTravelerError::InvalidReferrer
+    )]
+    pub referral: Option<Account<'info, ReferralAccount>>,
+
+    /// Mint of the pool, needed for checked transfers
+    #[account(address = pool.mint @ TimeTravelerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Temporary wSOL account for native-SOL pools, closed to the claimant
+    /// within the instruction so the payout arrives as lamports
+    #[account(
+        init,
+        payer = claimant,
+        token::mint = mint,
+        token::authority = pool,
+        token::token_program = token_program,
+        seeds = [b"unwrap", pool.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub unwrap_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+// claim instruction
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Pay a community pool creator the creator fees accrued from claims.
+/// Native-SOL pools pay the creator in lamports.
+pub fn handler(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    require!(pool.community, TimeTravelerError::NotCommunityPool);
+    let amount = pool.creator_fees;
+    require!(amount > 0, TimeTravelerError::NothingToClaim);
+
+    // Clear accrued fees before moving funds
+    pool.creator_fees = 0;
+
+    // Transfer fees from pool vault -> creator, signed by pool PDA
+    let pool_seeds = pool.signer_seeds();
+    let signer_seeds: &[&[&[u8]]] = &[&pool_seeds];
+    if pool.native_sol {
+        // Unwrap to lamports through a temporary wSOL account
+        let unwrap_account = ctx
+            .accounts
+            .unwrap_account
+            .as_ref()
+            .ok_or(TimeTravelerError::TokenAccountMissing)?;
+        unwrap_sol(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.pool_vault.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            unwrap_account.to_account_info(),
+            pool.to_account_info(),
+            ctx.accounts.creator.to_account_info(),
+            signer_seeds,
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+    } else {
+        let creator_token_account = ctx
+            .accounts
+            .creator_token_account
+            .as_ref()
+            .ok_or(TimeTravelerError::TokenAccountMissing)?;
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.pool_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: creator_token_account.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+    }
+
+    emit!(CreatorFeesClaimed {
+        pool: pool.key(),
+        creator: pool.authority,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimCreatorFees<'info> {
+    /// Creator of the community pool
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// The community pool
+    #[account(
+        mut,
+        constraint = pool.authority == creator.key() @ TimeTravelerError::Unauthorized,
+        constraint = pool.is_pda(&pool.key()) @ TimeTravelerError::InvalidPoolAddress
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Pool vault holding the accrued fees
+    #[account(
+        mut,
+        seeds = [b"vault", pool.key().as_ref()],
+        bump,
+        constraint = pool_vault.owner == pool.key() @ TimeTravelerError::InvalidVaultOwner,
+        constraint = pool_vault.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Creator’s token account receiving the fees; not used by native-SOL
+    /// pools, which pay out lamports
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Mint of the pool, needed for checked transfers
+    #[account(address = pool.mint @ TimeTravelerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Temporary wSOL account for native-SOL pools, closed to the creator
+    /// within the instruction so the fees arrive as lamports
+    #[account(
+        init,
+        payer = creator,
+        token::mint = mint,
+        token::authority = pool,
+        token::token_program = token_program,
+        seeds = [b"unwrap", pool.key().as_ref(), creator.key().as_ref()],
+        bump
+    )]
+    pub unwrap_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+// claim_creator_fees instruction
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Pay out a referrer's accrued rewards from the treasury of the referral mint.
+pub fn handler(ctx: Context<ClaimReferralRewards>) -> Result<()> {
+    let registry = &ctx.accounts.registry;
+    let referral = &mut ctx.accounts.referral;
+
+    let amount = safe_sub(referral.rewards_accrued, referral.rewards_claimed)?;
+    require!(amount > 0, TimeTravelerError::NothingToClaim);
+
+    // Mark claimed before moving funds
+    referral.rewards_claimed = referral.rewards_accrued;
+    let stats = &mut ctx.accounts.treasury_stats;
+    stats.referral_rewards_paid = safe_add(stats.referral_rewards_paid, amount)?;
+
+    // Transfer rewards from treasury -> referrer, signed by registry PDA
+    let signer_seeds: &[&[&[u8]]] = &[&[b"registry", &[registry.bump]]];
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.treasury.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.destination.to_account_info(),
+        authority: registry.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+    emit!(ReferralRewardsClaimed {
+        referrer: referral.referrer,
+        mint: referral.mint,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimReferralRewards<'info> {
+    /// Referrer claiming rewards
+    pub referrer: Signer<'info>,
+
+    #[account(
+        seeds = [b"registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// Referrer’s Referral PDA
+    #[account(
+        mut,
+        has_one = referrer @ TimeTravelerError::Unauthorized,
+        has_one = registry @ TimeTravelerError::InvalidRegistryReference,
+        seeds = [b"referral", registry.key().as_ref(), referral.mint.as_ref(), referrer.key().as_ref()],
+        bump = referral.bump
+    )]
+    pub referral: Account<'info, ReferralAccount>,
+
+    /// Treasury for the referral mint, funds the rewards
+    #[account(
+        mut,
+        seeds = [b"treasury", registry.key().as_ref(), referral.mint.as_ref()],
+        bump
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    /// Fee totals for the treasury
+    #[account(
+        mut,
+        seeds = [b"treasury_stats", registry.key().as_ref(), referral.mint.as_ref()],
+        bump = treasury_stats.bump
+    )]
+    pub treasury_stats: Account<'info, TreasuryStats>,
+
+    /// Token account receiving the rewards
+    #[account(
+        mut,
+        constraint = destination.mint == referral.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    /// Mint of the referral, needed for checked transfers
+    #[account(address = referral.mint @ TimeTravelerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+// claim_referral_rewards instruction
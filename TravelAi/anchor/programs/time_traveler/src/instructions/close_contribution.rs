@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Close a Contribution account whose state is final and return its rent to
+/// the contributor. A position is final once it is empty, claimed or
+/// refunded, or backs an outcome that pays nothing in a resolved pool.
+/// Callable by anyone.
+pub fn handler(ctx: Context<CloseContribution>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let contrib = &ctx.accounts.contribution;
+
+    let is_final = contrib.amount == 0
+        || contrib.claimed
+        || (matches!(pool.status, PoolStatus::Resolved | PoolStatus::Settled)
+            && !pool.outcome_pays(contrib.outcome)?);
+    require!(is_final, TimeTravelerError::PositionNotFinal);
+
+    pool.contribution_closed(contrib.amount)?;
+
+    emit!(ContributionClosed {
+        pool: pool.key(),
+        user: contrib.user,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseContribution<'info> {
+    /// Contributor receiving the rent
+    /// CHECK: must be the contribution owner
+    #[account(
+        mut,
+        address = contribution.user @ TimeTravelerError::InvalidContribution
+    )]
+    pub contributor: UncheckedAccount<'info>,
+
+    /// The pool the position belongs to
+    #[account(
+        mut,
+        constraint = pool.is_pda(&pool.key()) @ TimeTravelerError::InvalidPoolAddress
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Contributor’s Contribution PDA
+    #[account(
+        mut,
+        close = contributor,
+        seeds = [b"contrib", pool.key().as_ref(), contributor.key().as_ref()],
+        bump,
+        constraint = contribution.pool == pool.key() @ TimeTravelerError::InvalidContribution
+    )]
+    pub contribution: Account<'info, Contribution>,
+}
+// close_contribution instruction
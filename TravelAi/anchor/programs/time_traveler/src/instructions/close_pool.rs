@@ -0,0 +1,232 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Close a settled pool once `registry.close_grace_secs` have passed since
+/// `close_ts`. Every Contribution must be closed and any creation bond
+/// released first. Creator fees the creator has not claimed are paid to
+/// their token account, or as lamports for native-SOL pools, unwrapped
+/// through a temporary account funded by `payer`, who gets its rent back.
+/// What is left in the vault is then rounding dust and is sent to the
+/// registry treasury. The vault, metadata and pool rent returns to the pool
+/// authority.
+/// Callable by anyone.
+pub fn handler(ctx: Context<ClosePool>) -> Result<()> {
+    let registry = &ctx.accounts.registry;
+    let pool = &mut ctx.accounts.pool;
+
+    require!(
+        pool.status == PoolStatus::Settled,
+        TimeTravelerError::PoolNotSettled
+    );
+    require!(
+        !pool.has_outstanding_accounts(),
+        TimeTravelerError::PoolAccountsOutstanding
+    );
+    let closable_ts = pool
+        .close_ts
+        .checked_add(registry.close_grace_secs as i64)
+        .ok_or(TimeTravelerError::NumericalOverflow)?;
+    require!(
+        Clock::get()?.unix_timestamp >= closable_ts,
+        TimeTravelerError::CloseGracePeriodActive
+    );
+
+    // Clear outstanding creator fees before moving funds
+    let creator_fees = pool.creator_fees;
+    pool.creator_fees = 0;
+
+    let pool_seeds = pool.signer_seeds();
+    let signer_seeds: &[&[&[u8]]] = &[&pool_seeds];
+
+    // Pay unclaimed creator fees from pool vault -> creator
+    if creator_fees > 0 {
+        if pool.native_sol {
+            // Unwrap to lamports through a temporary wSOL account
+            let unwrap_account = ctx
+                .accounts
+                .unwrap_account
+                .as_ref()
+                .ok_or(TimeTravelerError::TokenAccountMissing)?;
+            unwrap_sol_to(
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.pool_vault.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                unwrap_account.to_account_info(),
+                pool.to_account_info(),
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.pool_authority.to_account_info(),
+                signer_seeds,
+                creator_fees,
+                ctx.accounts.mint.decimals,
+            )?;
+        } else {
+            let creator_token_account = ctx
+                .accounts
+                .creator_token_account
+                .as_ref()
+                .ok_or(TimeTravelerError::TokenAccountMissing)?;
+            let cpi_accounts = TransferChecked {
+                from: ctx.accounts.pool_vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: creator_token_account.to_account_info(),
+                authority: pool.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token_interface::transfer_checked(cpi_ctx, creator_fees, ctx.accounts.mint.decimals)?;
+        }
+        ctx.accounts.pool_vault.reload()?;
+
+        emit!(CreatorFeesClaimed {
+            pool: pool.key(),
+            creator: pool.authority,
+            amount: creator_fees,
+        });
+    }
+
+    // Sweep rounding dust from pool vault -> treasury
+    let dust = ctx.accounts.pool_vault.amount;
+    if dust > 0 {
+        let balance_before = ctx.accounts.treasury.amount;
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.pool_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.treasury.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_interface::transfer_checked(cpi_ctx, dust, ctx.accounts.mint.decimals)?;
+
+        // Count what arrived, net of any mint transfer fee
+        ctx.accounts.treasury.reload()?;
+        let received = safe_sub(ctx.accounts.treasury.amount, balance_before)?;
+        let stats = &mut ctx.accounts.treasury_stats;
+        stats.fees_collected = safe_add(stats.fees_collected, received)?;
+    }
+
+    // Close the empty vault, rent to the pool authority
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.pool_vault.to_account_info(),
+        destination: ctx.accounts.pool_authority.to_account_info(),
+        authority: pool.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token_interface::close_account(cpi_ctx)?;
+
+    emit!(PoolAccountsClosed {
+        pool: pool.key(),
+        dust,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClosePool<'info> {
+    /// Caller, funds the temporary unwrap account of native-SOL pools
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Global registry (grace period)
+    pub registry: Account<'info, Registry>,
+
+    /// Pool authority receiving the rent
+    /// CHECK: must be the pool authority
+    #[account(
+        mut,
+        address = pool.authority @ TimeTravelerError::Unauthorized
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    /// The settled pool
+    #[account(
+        mut,
+        close = pool_authority,
+        has_one = registry @ TimeTravelerError::InvalidRegistryReference,
+        constraint = pool.is_pda(&pool.key()) @ TimeTravelerError::InvalidPoolAddress
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// The pool's metadata PDA
+    #[account(
+        mut,
+        close = pool_authority,
+        seeds = [b"metadata", pool.key().as_ref()],
+        bump = metadata.bump
+    )]
+    pub metadata: Account<'info, PoolMetadata>,
+
+    /// Pool vault, emptied and closed
+    #[account(
+        mut,
+        seeds = [b"vault", pool.key().as_ref()],
+        bump,
+        constraint = pool_vault.owner == pool.key() @ TimeTravelerError::InvalidVaultOwner,
+        constraint = pool_vault.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Creator’s token account, receives unclaimed creator fees; only
+    /// required when the pool still holds some, and not used by native-SOL
+    /// pools, which pay them to the pool authority as lamports
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == pool.authority @ TimeTravelerError::Unauthorized,
+        constraint = creator_token_account.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Registry treasury for the pool mint, receives the dust
+    #[account(
+        mut,
+        seeds = [b"treasury", registry.key().as_ref(), pool.mint.as_ref()],
+        bump
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    /// Fee totals for the treasury
+    #[account(
+        mut,
+        seeds = [b"treasury_stats", registry.key().as_ref(), pool.mint.as_ref()],
+        bump = treasury_stats.bump
+    )]
+    pub treasury_stats: Account<'info, TreasuryStats>,
+
+    /// Mint of the pool, needed for checked transfers
+    #[account(address = pool.mint @ TimeTravelerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Temporary wSOL account for native-SOL pools, closed to the payer
+    /// within the instruction so creator fees reach the creator as lamports
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint,
+        token::authority = pool,
+        token::token_program = token_program,
+        seeds = [b"unwrap", pool.key().as_ref(), payer.key().as_ref()],
+        bump
+    )]
+    pub unwrap_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+// close_pool instruction
@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Contribute SPL tokens (e.g. $TRAVELAI) into an active prediction pool.
+/// Funds are transferred from the contributor’s token account to the pool vault
+/// with `transfer_checked`; for Token-2022 mints with a transfer fee only the
+/// amount the vault actually received is credited. Native-SOL pools wrap
+/// `amount` lamports from the contributor into the wSOL vault instead.
+/// Pool’s total_contributed, the chosen outcome’s total and contributor’s share are updated.
+/// A contributor backs a single outcome; top-ups must use the same outcome.
+/// The pool's minimum contribution, per-wallet cap and hard cap are enforced.
+/// When `referrer` is set, the position is tied to the referrer’s Referral
+/// account and its referred volume counters are bumped here. The referral
+/// reward is not credited here: the protocol fee of a position is only taken
+/// when its winnings are claimed, so a losing, refunded or withdrawn position
+/// pays no fee to share. `claim` credits `registry.referral_bps` of the fee
+/// the treasury actually received for this contribution instead.
+pub fn handler(
+    ctx: Context<Contribute>,
+    amount: u64,
+    outcome: u8,
+    referrer: Option<Pubkey>,
+) -> Result<()> {
+    require!(amount > 0, TimeTravelerError::InvalidAmount);
+
+    let pool = &mut ctx.accounts.pool;
+
+    // Ensure pool is open: after open_ts and before the lock period
+    match pool.sync_status(Clock::get()?.unix_timestamp) {
+        PoolStatus::Open => {}
+        PoolStatus::Scheduled => return err!(TimeTravelerError::PoolNotOpenYet),
+        PoolStatus::Locked => return err!(TimeTravelerError::PoolLocked),
+        _ => return err!(TimeTravelerError::PoolClosed),
+    }
+
+    require!(
+        outcome < pool.num_outcomes,
+        TimeTravelerError::InvalidOutcome
+    );
+
+    // A position cannot switch outcomes once funded
+    require!(
+        ctx.accounts.contribution.amount == 0 || ctx.accounts.contribution.outcome == outcome,
+        TimeTravelerError::OutcomeMismatch
+    );
+
+    let vault_before = ctx.accounts.pool_vault.amount;
+    if pool.native_sol {
+        // Wrap lamports from the contributor straight into the wSOL vault
+        wrap_sol(
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.contributor.to_account_info(),
+            ctx.accounts.pool_vault.to_account_info(),
+            amount,
+        )?;
+    } else {
+        // Transfer tokens from user -> pool vault
+        let user_token_account = ctx
+            .accounts
+            .user_token_account
+            .as_ref()
+            .ok_or(TimeTravelerError::TokenAccountMissing)?;
+        let cpi_accounts = TransferChecked {
+            from: user_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.pool_vault.to_account_info(),
+            authority: ctx.accounts.contributor.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+    }
+
+    // Credit what the vault actually received (Token-2022 transfer fees)
+    ctx.accounts.pool_vault.reload()?;
+    let received = safe_sub(ctx.accounts.pool_vault.amount, vault_before)?;
+    require!(received > 0, TimeTravelerError::InvalidAmount);
+
+    pool.limits.check(
+        received,
+        safe_add(ctx.accounts.contribution.amount, received)?,
+        safe_add(pool.total_contributed, received)?,
+    )?;
+
+    // Update pool totals
+    pool.total_contributed = safe_add(pool.total_contributed, received)?;
+    let bucket = &mut pool.buckets[outcome as usize];
+    bucket.total = safe_add(bucket.total, received)?;
+
+    // Record contributor stats
+    let contrib = &mut ctx.accounts.contribution;
+    if contrib.pool == Pubkey::default() {
+        pool.contribution_opened()?;
+    }
+    if contrib.amount == 0 {
+        pool.contributors = safe_add(pool.contributors, 1)?;
+    }
+    contrib.pool = pool.key();
+    contrib.user = ctx.accounts.contributor.key();
+    contrib.outcome = outcome;
+    contrib.amount = safe_add(contrib.amount, received)?;
+
+    // Tie the position to the referrer; rewards accrue when the fee is collected
+    if let Some(referrer) = referrer {
+        require!(
+            referrer != ctx.accounts.contributor.key(),
+            TimeTravelerError::SelfReferral
+        );
+        let referral = ctx
+            .accounts
+            .referral
+            .as_mut()
+            .ok_or(TimeTravelerError::InvalidReferrer)?;
+        require!(
+            referral.referrer == referrer,
+            TimeTravelerError::InvalidReferrer
+        );
+        // A position keeps the referrer it was opened with
+        require!(
+            contrib.referral == Pubkey::default() || contrib.referral == referral.key(),
+            TimeTravelerError::InvalidReferrer
+        );
+
+        contrib.referral = referral.key();
+        referral.referred_volume = safe_add(referral.referred_volume, received)?;
+        referral.referred_contributions = safe_add(referral.referred_contributions, 1)?;
+    }
+
+    emit!(Contributed {
+        pool: pool.key(),
+        user: contrib.user,
+        outcome,
+        amount: received,
+        total_contributed: pool.total_contributed,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Contribute<'info> {
+    /// The contributor (payer of tx, signer)
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    /// Pool state account (must be mutable, seeded PDA)
+    #[account(
+        mut,
+        has_one = registry @ TimeTravelerError::InvalidRegistryReference,
+        constraint = !pool.paused @ TimeTravelerError::PoolPaused
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Global registry (for reference & authority checks)
+    #[account(
+        constraint = !registry.paused @ TimeTravelerError::ProgramPaused
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// Contributor’s PDA Contribution account
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = 8 + Contribution::SIZE,
+        seeds = [b"contrib", pool.key().as_ref(), contributor.key().as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    /// Contributor’s token account (must be $TRAVELAI mint); not used by
+    /// native-SOL pools, which take lamports from the contributor
+    #[account(
+        mut,
+        constraint = user_token_account.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub user_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pool vault (PDA-owned token account that holds all contributions)
+    #[account(
+        mut,
+        constraint = pool_vault.mint == pool.mint @ TimeTravelerError::InvalidMint,
+        constraint = pool_vault.owner == pool.key() @ TimeTravelerError::InvalidVaultOwner
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Referrer’s Referral PDA, required when a referrer is named
+    #[account(
+        mut,
+        has_one = registry @ TimeTravelerError::InvalidRegistryReference,
+        constraint = referral.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub referral: Option<Account<'info, ReferralAccount>>,
+
+    #[account(address = pool.mint @ TimeTravelerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub rent: Sysvar<'info, Rent>,
+}
+// contribute instruction
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+use super::create_pool::{init_pool, PoolParams};
+
+/// Create a pool without a registry role by locking `registry.community_bond`
+/// of the pool mint in the pool vault. The bond is returned through
+/// `release_creation_bond` once the pool is resolved or cancelled, unless it
+/// was cancelled as invalid, in which case it goes to the treasury.
+/// The creator earns `registry.creator_fee_bps` of every claim.
+/// `metadata` describes the pool in its PoolMetadata account.
+/// Resolution stays with the registry Resolver / oracle like any other pool.
+/// Community pools cannot link a timeline prediction.
+/// The registry treasury for the mint must already exist.
+/// The pool PDA is namespaced by creator, so community pools never occupy an
+/// official pool's address for the same signal.
+/// Callable by anyone.
+pub fn handler(
+    ctx: Context<CreateCommunityPool>,
+    signal_id: [u8; 32],
+    params: PoolParams,
+    metadata: PoolMetadataParams,
+) -> Result<()> {
+    require!(
+        params.timeline_prediction_id.is_none(),
+        TimeTravelerError::TimelineLinkNotAllowed
+    );
+
+    let native_sol = params.native_sol;
+    init_pool(
+        &mut ctx.accounts.pool,
+        &ctx.accounts.registry,
+        ctx.accounts.creator.key(),
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        signal_id,
+        params,
+    )?;
+
+    // Lock the creation bond in the pool vault
+    let bond = ctx.accounts.registry.community_bond;
+    let mut received = 0;
+    if bond > 0 {
+        if native_sol {
+            wrap_sol(
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.creator.to_account_info(),
+                ctx.accounts.pool_vault.to_account_info(),
+                bond,
+            )?;
+        } else {
+            let creator_token_account = ctx
+                .accounts
+                .creator_token_account
+                .as_ref()
+                .ok_or(TimeTravelerError::TokenAccountMissing)?;
+            let cpi_accounts = TransferChecked {
+                from: creator_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.pool_vault.to_account_info(),
+                authority: ctx.accounts.creator.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+            token_interface::transfer_checked(cpi_ctx, bond, ctx.accounts.mint.decimals)?;
+        }
+
+        // Hold only what the vault received (Token-2022 transfer fees)
+        ctx.accounts.pool_vault.reload()?;
+        received = ctx.accounts.pool_vault.amount;
+    }
+
+    let pool = &mut ctx.accounts.pool;
+    pool.community = true;
+    pool.creation_bond = received;
+    pool.creator_fee_bps = ctx.accounts.registry.creator_fee_bps;
+    pool.bump = *ctx.bumps.get("pool").unwrap();
+
+    let pool_metadata = &mut ctx.accounts.metadata;
+    pool_metadata.pool = pool.key();
+    pool_metadata.apply(metadata)?;
+    pool_metadata.bump = *ctx.bumps.get("metadata").unwrap();
+
+    emit!(CommunityPoolCreated {
+        pool: pool.key(),
+        creator: pool.authority,
+        creation_bond: pool.creation_bond,
+        creator_fee_bps: pool.creator_fee_bps,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(signal_id: [u8; 32])]
+pub struct CreateCommunityPool<'info> {
+    /// Community member creating the pool and posting the bond
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// Global registry (bond and creator fee configuration)
+    #[account(
+        constraint = !registry.paused @ TimeTravelerError::ProgramPaused
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// Pool state account (PDA)
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Pool::SIZE,
+        seeds = [COMMUNITY_POOL_SEED, creator.key().as_ref(), signal_id.as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Descriptive metadata for the pool (PDA)
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + PoolMetadata::SIZE,
+        seeds = [b"metadata", pool.key().as_ref()],
+        bump
+    )]
+    pub metadata: Account<'info, PoolMetadata>,
+
+    /// Token mint used for this pool
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Fee totals of the treasury for the pool mint; `init_treasury` must
+    /// have run for the mint so claims can collect the protocol fee
+    #[account(
+        seeds = [b"treasury_stats", registry.key().as_ref(), mint.key().as_ref()],
+        bump = treasury_stats.bump
+    )]
+    pub treasury_stats: Account<'info, TreasuryStats>,
+
+    /// Vault PDA token account where contributions and the bond are stored
+    #[account(
+        init,
+        payer = creator,
+        token::mint = mint,
+        token::authority = pool,
+        token::token_program = token_program,
+        seeds = [b"vault", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Creator’s token account funding the bond; not used by native-SOL
+    /// pools, which take lamports from the creator
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == mint.key() @ TimeTravelerError::InvalidMint
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+// create_community_pool instruction
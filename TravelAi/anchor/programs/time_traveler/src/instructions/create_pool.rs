@@ -0,0 +1,253 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Create a new prediction pool for a given signal.
+/// Each entry of `params.outcome_labels` declares one outcome bucket (hash of
+/// its label); binary YES/NO pools pass two labels. `kind` selects categorical
+/// resolution or numeric resolution against range buckets / a short-long
+/// scale, and `resolution_source` whether the result comes from the authority
+/// or the registry oracle. `limits` and `lock_period_secs` override the
+/// registry defaults. `native_sol` pools use the wrapped SOL mint but take
+/// and pay out lamports. `timeline_prediction_id` links the pool to the
+/// timeline archive record of its signal; only role-gated pools may set it.
+/// `metadata` describes the pool in its PoolMetadata account.
+/// The registry treasury for the mint must already exist.
+/// Callable by the registry authority or a PoolCreator.
+pub fn handler(
+    ctx: Context<CreatePool>,
+    signal_id: [u8; 32],
+    params: PoolParams,
+    metadata: PoolMetadataParams,
+) -> Result<()> {
+    init_pool(
+        &mut ctx.accounts.pool,
+        &ctx.accounts.registry,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        signal_id,
+        params,
+    )?;
+    ctx.accounts.pool.bump = *ctx.bumps.get("pool").unwrap();
+
+    let pool_metadata = &mut ctx.accounts.metadata;
+    pool_metadata.pool = ctx.accounts.pool.key();
+    pool_metadata.apply(metadata)?;
+    pool_metadata.bump = *ctx.bumps.get("metadata").unwrap();
+
+    Ok(())
+}
+
+/// Validate `params` and initialize a freshly created pool account.
+/// Shared by `create_pool` and `create_community_pool`.
+pub(crate) fn init_pool(
+    pool: &mut Account<Pool>,
+    registry: &Account<Registry>,
+    authority: Pubkey,
+    mint: &InterfaceAccount<Mint>,
+    token_program: &Interface<TokenInterface>,
+    signal_id: [u8; 32],
+    params: PoolParams,
+) -> Result<()> {
+    let PoolParams {
+        open_ts,
+        close_ts,
+        outcome_labels,
+        kind,
+        resolution_source,
+        limits,
+        lock_period_secs,
+        native_sol,
+        timeline_prediction_id,
+    } = params;
+
+    // Validate timestamps
+    require!(open_ts < close_ts, TimeTravelerError::InvalidTimestamps);
+    require!(close_ts > Clock::get()?.unix_timestamp, TimeTravelerError::PoolCloseInPast);
+    require!(
+        (2..=MAX_OUTCOMES).contains(&outcome_labels.len()),
+        TimeTravelerError::InvalidOutcomeCount
+    );
+    match kind {
+        PoolKind::Categorical => {}
+        PoolKind::Range { lower, upper } => {
+            require!(lower < upper, TimeTravelerError::InvalidBounds);
+        }
+        PoolKind::Scalar { lower, upper } => {
+            require!(lower < upper, TimeTravelerError::InvalidBounds);
+            require!(outcome_labels.len() == 2, TimeTravelerError::InvalidOutcomeCount);
+        }
+    }
+    if let ResolutionSource::Oracle { .. } = resolution_source {
+        require!(
+            registry.oracle != Pubkey::default(),
+            TimeTravelerError::OracleNotConfigured
+        );
+        // Oracle targets split categorical pools into YES/NO only
+        require!(
+            kind != PoolKind::Categorical || outcome_labels.len() == 2,
+            TimeTravelerError::InvalidOutcomeCount
+        );
+    }
+
+    if native_sol {
+        require!(
+            is_wrapped_sol(&mint.key(), &token_program.key()),
+            TimeTravelerError::InvalidMint
+        );
+    }
+
+    let limits = limits.unwrap_or(registry.default_limits);
+    require!(limits.is_valid(), TimeTravelerError::InvalidConfig);
+    let lock_period_secs =
+        lock_period_secs.unwrap_or(registry.default_lock_period_secs);
+    require!(
+        (lock_period_secs as i64) < close_ts - open_ts,
+        TimeTravelerError::InvalidTimestamps
+    );
+
+    pool.registry = registry.key();
+    pool.authority = authority;
+    pool.mint = mint.key();
+    pool.native_sol = native_sol;
+    pool.signal_id = signal_id;
+    pool.status = PoolStatus::Scheduled;
+    pool.kind = kind;
+    pool.resolution_source = resolution_source;
+    pool.open_ts = open_ts;
+    pool.close_ts = close_ts;
+    pool.lock_period_secs = lock_period_secs;
+    pool.dispute_deadline = 0;
+    pool.disputer = Pubkey::default();
+    pool.dispute_bond = 0;
+    pool.winning_outcome = None;
+    pool.resolved_value = None;
+    pool.total_contributed = 0;
+    pool.unsettled_stake = 0;
+    pool.contributors = 0;
+    pool.open_contributions = 0;
+    pool.num_outcomes = outcome_labels.len() as u8;
+    pool.paused = false;
+    pool.limits = limits;
+    pool.community = false;
+    pool.creation_bond = 0;
+    pool.creator_fee_bps = 0;
+    pool.creator_fees = 0;
+    pool.invalid = false;
+    pool.timeline_prediction_id = timeline_prediction_id;
+    pool.buckets = [OutcomeBucket::default(); MAX_OUTCOMES];
+    for (bucket, label_hash) in pool.buckets.iter_mut().zip(outcome_labels) {
+        bucket.label_hash = label_hash;
+    }
+    pool.sync_status(Clock::get()?.unix_timestamp);
+
+    emit!(PoolCreated {
+        pool: pool.key(),
+        signal_id,
+        mint: pool.mint,
+        open_ts,
+        close_ts,
+        num_outcomes: pool.num_outcomes,
+        kind,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(signal_id: [u8; 32])]
+pub struct CreatePool<'info> {
+    /// Registry authority or a PoolCreator creating the pool
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Global registry storing program authority and config
+    #[account(
+        constraint = registry.has_role(authority.key, roles.as_deref(), Role::PoolCreator) @ TimeTravelerError::Unauthorized,
+        constraint = !registry.paused @ TimeTravelerError::ProgramPaused,
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// Role table; only needed when the signer is not the registry authority
+    #[account(
+        seeds = [b"roles", registry.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Option<Account<'info, Roles>>,
+
+    /// Pool state account (PDA)
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Pool::SIZE,
+        seeds = [POOL_SEED, signal_id.as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Descriptive metadata for the pool (PDA)
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PoolMetadata::SIZE,
+        seeds = [b"metadata", pool.key().as_ref()],
+        bump
+    )]
+    pub metadata: Account<'info, PoolMetadata>,
+
+    /// Token mint ($TRAVELAI) used for this pool
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Fee totals of the treasury for the pool mint; `init_treasury` must
+    /// have run for the mint so claims can collect the protocol fee
+    #[account(
+        seeds = [b"treasury_stats", registry.key().as_ref(), mint.key().as_ref()],
+        bump = treasury_stats.bump
+    )]
+    pub treasury_stats: Account<'info, TreasuryStats>,
+
+    /// Vault PDA token account where contributions are stored
+    #[account(
+        init,
+        payer = authority,
+        token::mint = mint,
+        token::authority = pool,
+        token::token_program = token_program,
+        seeds = [b"vault", pool.key().as_ref()],
+        bump
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Pool configuration supplied at creation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PoolParams {
+    /// Contributions open at this unix timestamp
+    pub open_ts: i64,
+    /// Contributions close and resolution may start at this unix timestamp
+    pub close_ts: i64,
+    /// One label hash per outcome bucket (2..=MAX_OUTCOMES)
+    pub outcome_labels: Vec<[u8; 32]>,
+    /// How buckets map to a result
+    pub kind: PoolKind,
+    /// Where the result comes from
+    pub resolution_source: ResolutionSource,
+    /// Contribution limits (None = registry defaults)
+    pub limits: Option<ContributionLimits>,
+    /// Seconds before close_ts with no new contributions (None = registry default)
+    pub lock_period_secs: Option<u32>,
+    /// Take and pay out lamports, holding them as wSOL (mint must be wrapped SOL)
+    pub native_sol: bool,
+    /// Timeline PredictionRecord this pool backs, updated when the pool closes
+    pub timeline_prediction_id: Option<u64>,
+}
+// create_pool instruction
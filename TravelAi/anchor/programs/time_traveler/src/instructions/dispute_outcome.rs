@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Challenge a proposed outcome before its dispute deadline.
+/// The disputer posts `registry.dispute_bond` of the pool mint into the pool
+/// vault; the arbiter refunds it if the proposal is overturned and slashes it
+/// to the treasury otherwise. The arbiter has `registry.arbitration_window_secs`
+/// to settle; after that `expire_dispute` cancels the pool. Callable by anyone.
+pub fn handler(ctx: Context<DisputeOutcome>) -> Result<()> {
+    let registry = &ctx.accounts.registry;
+    let pool = &mut ctx.accounts.pool;
+
+    require!(
+        pool.status == PoolStatus::Proposed,
+        TimeTravelerError::PoolNotProposed
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now < pool.dispute_deadline,
+        TimeTravelerError::DisputeWindowClosed
+    );
+    require!(
+        registry.arbiter != Pubkey::default(),
+        TimeTravelerError::ArbiterNotConfigured
+    );
+
+    // Post bond from disputer -> pool vault
+    let mut bond = registry.dispute_bond;
+    if bond > 0 {
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.disputer_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.pool_vault.to_account_info(),
+            authority: ctx.accounts.disputer.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        let vault_before = ctx.accounts.pool_vault.amount;
+        token_interface::transfer_checked(cpi_ctx, bond, ctx.accounts.mint.decimals)?;
+
+        // Hold only what the vault received (Token-2022 transfer fees)
+        ctx.accounts.pool_vault.reload()?;
+        bond = safe_sub(ctx.accounts.pool_vault.amount, vault_before)?;
+    }
+
+    pool.status = PoolStatus::Disputed;
+    pool.disputer = ctx.accounts.disputer.key();
+    pool.dispute_bond = bond;
+    pool.dispute_deadline = now
+        .checked_add(registry.arbitration_window_secs as i64)
+        .ok_or(TimeTravelerError::NumericalOverflow)?;
+
+    emit!(OutcomeDisputed {
+        pool: pool.key(),
+        disputer: pool.disputer,
+        bond,
+        arbitration_deadline: pool.dispute_deadline,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DisputeOutcome<'info> {
+    /// Account challenging the proposal and posting the bond
+    pub disputer: Signer<'info>,
+
+    /// Global registry (dispute bond and arbiter)
+    #[account(
+        constraint = !registry.paused @ TimeTravelerError::ProgramPaused
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// The pool whose proposal is disputed
+    #[account(
+        mut,
+        has_one = registry @ TimeTravelerError::InvalidRegistryReference,
+        constraint = !pool.paused @ TimeTravelerError::PoolPaused,
+        constraint = pool.is_pda(&pool.key()) @ TimeTravelerError::InvalidPoolAddress
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Pool vault holding the bond until the dispute is settled
+    #[account(
+        mut,
+        seeds = [b"vault", pool.key().as_ref()],
+        bump,
+        constraint = pool_vault.owner == pool.key() @ TimeTravelerError::InvalidVaultOwner,
+        constraint = pool_vault.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Disputer’s token account funding the bond
+    #[account(
+        mut,
+        constraint = disputer_token_account.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub disputer_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Mint of the pool, needed for checked transfers
+    #[account(address = pool.mint @ TimeTravelerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+// dispute_outcome instruction
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Cancel a disputed pool once its arbitration deadline has passed without a
+/// ruling. The dispute bond is refunded to the disputer and contributions are
+/// returned through `refund`.
+/// Callable by anyone.
+pub fn handler(ctx: Context<ExpireDispute>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    require!(
+        pool.status == PoolStatus::Disputed,
+        TimeTravelerError::PoolNotDisputed
+    );
+    require!(
+        Clock::get()?.unix_timestamp >= pool.dispute_deadline,
+        TimeTravelerError::ArbitrationWindowOpen
+    );
+
+    // Refund the bond from pool vault -> disputer, signed by pool PDA
+    let bond = pool.dispute_bond;
+    pool.dispute_bond = 0;
+    if bond > 0 {
+        let pool_seeds = pool.signer_seeds();
+        let signer_seeds: &[&[&[u8]]] = &[&pool_seeds];
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.pool_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.disputer_token_account.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_interface::transfer_checked(cpi_ctx, bond, ctx.accounts.mint.decimals)?;
+    }
+
+    pool.mark_cancelled()?;
+
+    emit!(DisputeExpired {
+        pool: pool.key(),
+        disputer: pool.disputer,
+        bond,
+    });
+
+    emit!(PoolCancelled {
+        pool: pool.key(),
+        signal_id: pool.signal_id,
+        total_contributed: pool.total_contributed,
+        invalid: false,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExpireDispute<'info> {
+    /// Global registry
+    #[account(
+        constraint = !registry.paused @ TimeTravelerError::ProgramPaused
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// The disputed pool
+    #[account(
+        mut,
+        has_one = registry @ TimeTravelerError::InvalidRegistryReference,
+        constraint = !pool.paused @ TimeTravelerError::PoolPaused,
+        constraint = pool.is_pda(&pool.key()) @ TimeTravelerError::InvalidPoolAddress
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Pool vault holding the dispute bond
+    #[account(
+        mut,
+        seeds = [b"vault", pool.key().as_ref()],
+        bump,
+        constraint = pool_vault.owner == pool.key() @ TimeTravelerError::InvalidVaultOwner,
+        constraint = pool_vault.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Disputer’s token account, receives the bond back
+    #[account(
+        mut,
+        constraint = disputer_token_account.owner == pool.disputer @ TimeTravelerError::Unauthorized,
+        constraint = disputer_token_account.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub disputer_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Mint of the pool, needed for checked transfers
+    #[account(address = pool.mint @ TimeTravelerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+// expire_dispute instruction
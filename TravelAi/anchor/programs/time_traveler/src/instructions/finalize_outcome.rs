@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Close a pool on its proposed outcome once the dispute window has passed
+/// without a dispute. Pools linked to a timeline prediction mirror the result
+/// into it through `mirror_pool_result`. Callable by anyone.
+pub fn handler(ctx: Context<FinalizeOutcome>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    require!(
+        pool.status == PoolStatus::Proposed,
+        TimeTravelerError::PoolNotProposed
+    );
+    require!(
+        Clock::get()?.unix_timestamp >= pool.dispute_deadline,
+        TimeTravelerError::DisputeWindowOpen
+    );
+
+    pool.mark_resolved()?;
+
+    // Mirror the result into the timeline archive
+    mirror_pool_result(
+        pool,
+        &ctx.accounts.registry,
+        ctx.accounts.timeline_program.as_ref(),
+        ctx.accounts.timeline_archive.as_ref(),
+        ctx.accounts.timeline_writer.as_ref(),
+        ctx.accounts.timeline_prediction.as_ref(),
+    )?;
+
+    emit!(PoolClosed {
+        pool: pool.key(),
+        signal_id: pool.signal_id,
+        winning_outcome: pool.winning_outcome,
+        resolved_value: pool.resolved_value,
+        total_contributed: pool.total_contributed,
+        refunded: pool.status != PoolStatus::Resolved,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FinalizeOutcome<'info> {
+    /// Global registry (pause switch)
+    #[account(
+        constraint = !registry.paused @ TimeTravelerError::ProgramPaused
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// The pool whose proposal is finalized
+    #[account(
+        mut,
+        has_one = registry @ TimeTravelerError::InvalidRegistryReference,
+        constraint = !pool.paused @ TimeTravelerError::PoolPaused
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// CHECK: Timeline program configured on the registry; only required for
+    /// pools linked to a timeline prediction.
+    #[account(
+        executable,
+        constraint = timeline_program.key() == registry.timeline_program @ TimeTravelerError::InvalidTimelineAccount
+    )]
+    pub timeline_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Timeline Archive PDA; address checked in `mirror_pool_result`
+    /// and validated by the timeline program.
+    pub timeline_archive: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Timeline ArchiveWriter PDA naming the registry PDA as writer;
+    /// address checked in `mirror_pool_result` and validated by the timeline program.
+    pub timeline_writer: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Timeline PredictionRecord PDA for `pool.timeline_prediction_id`;
+    /// address checked in `mirror_pool_result` and validated by the timeline program.
+    #[account(mut)]
+    pub timeline_prediction: Option<UncheckedAccount<'info>>,
+}
+// finalize_outcome instruction
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Grant `role` to `member`.
+/// Only callable by registry authority.
+pub fn handler(ctx: Context<GrantRole>, member: Pubkey, role: Role) -> Result<()> {
+    let roles = &mut ctx.accounts.roles;
+
+    roles.grant(member, role)?;
+
+    emit!(RoleGranted {
+        registry: roles.registry,
+        member,
+        role,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct GrantRole<'info> {
+    /// Current authority of the program
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority @ TimeTravelerError::Unauthorized
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// Role table of the registry
+    #[account(
+        mut,
+        seeds = [b"roles", registry.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Account<'info, Roles>,
+}
+// grant_role instruction
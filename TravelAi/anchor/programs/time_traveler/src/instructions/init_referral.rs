@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::*;
+
+/// Register the signer as a referrer for pools using `mint`.
+/// Contributions naming this referrer credit rewards to the account.
+pub fn handler(ctx: Context<InitReferral>) -> Result<()> {
+    let referral = &mut ctx.accounts.referral;
+
+    referral.registry = ctx.accounts.registry.key();
+    referral.referrer = ctx.accounts.referrer.key();
+    referral.mint = ctx.accounts.mint.key();
+    referral.referred_volume = 0;
+    referral.referred_contributions = 0;
+    referral.rewards_accrued = 0;
+    referral.rewards_claimed = 0;
+    referral.bump = *ctx.bumps.get("referral").unwrap();
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitReferral<'info> {
+    /// Referrer registering (pays rent)
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        seeds = [b"registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// Token mint rewards are paid in
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Referral PDA account
+    #[account(
+        init,
+        payer = referrer,
+        space = 8 + ReferralAccount::SIZE,
+        seeds = [b"referral", registry.key().as_ref(), mint.key().as_ref(), referrer.key().as_ref()],
+        bump
+    )]
+    pub referral: Account<'info, ReferralAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+// init_referral instruction
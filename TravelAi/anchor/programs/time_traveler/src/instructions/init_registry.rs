@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::events::*;
+
+/// Default oracle guards until the authority calls `set_config`
+pub const DEFAULT_MAX_PRICE_AGE_SECS: u32 = 60;
+pub const DEFAULT_MAX_CONF_BPS: u16 = 200;
+pub const DEFAULT_MAX_DEVIATION_BPS: u16 = 100;
+pub const DEFAULT_DISPUTE_WINDOW_SECS: u32 = 86_400;
+pub const DEFAULT_ARBITRATION_WINDOW_SECS: u32 = 604_800;
+pub const DEFAULT_EARLY_EXIT_PENALTY_BPS: u16 = 100;
+pub const DEFAULT_EXIT_LOCKOUT_SECS: u32 = 3_600;
+pub const DEFAULT_LOCK_PERIOD_SECS: u32 = 300;
+pub const DEFAULT_CLOSE_GRACE_SECS: u32 = 604_800;
+
+/// Initialize the global Registry account for TimeTravelerAI.
+/// Can only be called once (PDA must be uninitialized).
+pub fn handler(ctx: Context<InitRegistry>, authority: Pubkey) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    registry.authority = authority;
+    registry.pending_authority = Pubkey::default();
+    registry.version = 1;
+    registry.fee_bps = 0;
+    registry.oracle = Pubkey::default();
+    registry.oracle_type = OracleType::Pyth;
+    registry.secondary_oracle = Pubkey::default();
+    registry.secondary_oracle_type = OracleType::Pyth;
+    registry.max_price_age_secs = DEFAULT_MAX_PRICE_AGE_SECS;
+    registry.max_conf_bps = DEFAULT_MAX_CONF_BPS;
+    registry.max_deviation_bps = DEFAULT_MAX_DEVIATION_BPS;
+    registry.referral_bps = 0;
+    registry.dispute_window_secs = DEFAULT_DISPUTE_WINDOW_SECS;
+    registry.dispute_bond = 0;
+    registry.arbiter = Pubkey::default();
+    registry.arbitration_window_secs = DEFAULT_ARBITRATION_WINDOW_SECS;
+    registry.early_exit_penalty_bps = DEFAULT_EARLY_EXIT_PENALTY_BPS;
+    registry.exit_lockout_secs = DEFAULT_EXIT_LOCKOUT_SECS;
+    registry.default_limits = ContributionLimits::default();
+    registry.default_lock_period_secs = DEFAULT_LOCK_PERIOD_SECS;
+    registry.community_bond = 0;
+    registry.creator_fee_bps = 0;
+    registry.timeline_program = Pubkey::default();
+    registry.close_grace_secs = DEFAULT_CLOSE_GRACE_SECS;
+    registry.paused = false;
+    registry.config_version = 0;
+    registry.bump = *ctx.bumps.get("registry").unwrap();
+
+    emit!(RegistryInitialized {
+        registry: registry.key(),
+        authority,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct InitRegistry<'info> {
+    /// Payer who funds account rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The Registry PDA account, created once
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Registry::SIZE,
+        seeds = [b"registry"],
+        bump
+    )]
+    pub registry: Account<'info, Registry>,
+
+    pub system_program: Program<'info, System>,
+}
+// init_registry instruction
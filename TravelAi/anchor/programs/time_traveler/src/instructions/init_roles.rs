@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+
+/// Create the empty role table of the registry.
+/// Only callable by registry authority.
+pub fn handler(ctx: Context<InitRoles>) -> Result<()> {
+    let roles = &mut ctx.accounts.roles;
+
+    roles.registry = ctx.accounts.registry.key();
+    roles.members = [RoleMember::default(); MAX_ROLE_MEMBERS];
+    roles.bump = *ctx.bumps.get("roles").unwrap();
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitRoles<'info> {
+    /// Authority paying for the role table
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority @ TimeTravelerError::Unauthorized,
+        seeds = [b"registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// Roles PDA account, created once
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Roles::SIZE,
+        seeds = [b"roles", registry.key().as_ref()],
+        bump
+    )]
+    pub roles: Account<'info, Roles>,
+
+    pub system_program: Program<'info, System>,
+}
+// init_roles instruction
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::state::*;
+use crate::errors::*;
+
+/// Create the registry-owned treasury token account for a mint, and the
+/// stats account tracking its fee totals.
+/// Protocol fees of every pool using this mint are collected here.
+/// Callable by the registry authority or a FeeManager.
+pub fn handler(ctx: Context<InitTreasury>) -> Result<()> {
+    let stats = &mut ctx.accounts.treasury_stats;
+
+    stats.registry = ctx.accounts.registry.key();
+    stats.mint = ctx.accounts.mint.key();
+    stats.fees_collected = 0;
+    stats.fees_withdrawn = 0;
+    stats.referral_rewards_accrued = 0;
+    stats.referral_rewards_paid = 0;
+    stats.bump = *ctx.bumps.get("treasury_stats").unwrap();
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitTreasury<'info> {
+    /// Registry authority or a FeeManager paying for the treasury account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = registry.has_role(authority.key, roles.as_deref(), Role::FeeManager) @ TimeTravelerError::Unauthorized,
+        seeds = [b"registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// Role table; only needed when the signer is not the registry authority
+    #[account(
+        seeds = [b"roles", registry.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Option<Account<'info, Roles>>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Treasury PDA token account, owned by the registry
+    #[account(
+        init,
+        payer = authority,
+        token::mint = mint,
+        token::authority = registry,
+        token::token_program = token_program,
+        seeds = [b"treasury", registry.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    /// Fee totals for the treasury
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + TreasuryStats::SIZE,
+        seeds = [b"treasury_stats", registry.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub treasury_stats: Account<'info, TreasuryStats>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub rent: Sysvar<'info, Rent>,
+}
+// init_treasury instruction
@@ -1,14 +1,71 @@
+// Every instruction module exports a `handler`; callers use the full path.
+#![allow(ambiguous_glob_reexports)]
+
 pub mod init_registry;
-pub mod set_authority;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod cancel_authority_transfer;
 pub mod set_config;
+pub mod init_roles;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod set_registry_pause;
+pub mod set_pool_pause;
 pub mod create_pool;
+pub mod create_community_pool;
+pub mod release_creation_bond;
+pub mod claim_creator_fees;
+pub mod update_pool_metadata;
+pub mod sync_pool_status;
 pub mod contribute;
+pub mod withdraw_contribution;
 pub mod verify_and_close_pool;
+pub mod propose_outcome;
+pub mod dispute_outcome;
+pub mod finalize_outcome;
+pub mod arbitrate;
+pub mod expire_dispute;
+pub mod claim;
+pub mod cancel_pool;
+pub mod refund;
+pub mod close_contribution;
+pub mod close_pool;
+pub mod init_treasury;
+pub mod withdraw_fees;
+pub mod init_referral;
+pub mod claim_referral_rewards;
 
 pub use init_registry::*;
-pub use set_authority::*;
+pub use propose_authority::*;
+pub use accept_authority::*;
+pub use cancel_authority_transfer::*;
 pub use set_config::*;
+pub use init_roles::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use set_registry_pause::*;
+pub use set_pool_pause::*;
 pub use create_pool::*;
+pub use create_community_pool::*;
+pub use release_creation_bond::*;
+pub use claim_creator_fees::*;
+pub use update_pool_metadata::*;
+pub use sync_pool_status::*;
 pub use contribute::*;
+pub use withdraw_contribution::*;
 pub use verify_and_close_pool::*;
+pub use propose_outcome::*;
+pub use dispute_outcome::*;
+pub use finalize_outcome::*;
+pub use arbitrate::*;
+pub use expire_dispute::*;
+pub use claim::*;
+pub use cancel_pool::*;
+pub use refund::*;
+pub use close_contribution::*;
+pub use close_pool::*;
+pub use init_treasury::*;
+pub use withdraw_fees::*;
+pub use init_referral::*;
+pub use claim_referral_rewards::*;
 // mod.rs for instructions
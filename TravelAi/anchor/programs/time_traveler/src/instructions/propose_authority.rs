@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Nominate a new Registry authority.
+/// The nominee takes over only once it signs `accept_authority`; until then
+/// the current authority stays in control and may cancel the nomination.
+/// Nominating again replaces any pending nominee.
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    // Ensure the new authority isn't the same as current
+    require!(
+        registry.authority != new_authority,
+        TimeTravelerError::NoAuthorityChange
+    );
+    require!(
+        new_authority != Pubkey::default(),
+        TimeTravelerError::InvalidConfig
+    );
+
+    registry.pending_authority = new_authority;
+
+    emit!(AuthorityProposed {
+        registry: registry.key(),
+        authority: registry.authority,
+        pending_authority: new_authority,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    /// The current authority of the registry
+    pub authority: Signer<'info>,
+
+    /// Global registry (authority must match signer)
+    #[account(
+        mut,
+        has_one = authority @ TimeTravelerError::Unauthorized
+    )]
+    pub registry: Account<'info, Registry>,
+}
+// propose_authority instruction
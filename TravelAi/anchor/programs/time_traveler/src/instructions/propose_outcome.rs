@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Propose the outcome of an authority-sourced pool after it closes.
+/// The proposal opens a dispute window of `registry.dispute_window_secs`;
+/// it becomes final through `finalize_outcome` unless disputed in time.
+/// Callable by the registry authority or a Resolver.
+pub fn handler(ctx: Context<ProposeOutcome>, resolution: Resolution) -> Result<()> {
+    let registry = &ctx.accounts.registry;
+    let pool = &mut ctx.accounts.pool;
+
+    require!(pool.is_unresolved(), TimeTravelerError::PoolAlreadyClosed);
+    require!(
+        pool.resolution_source == ResolutionSource::Authority,
+        TimeTravelerError::ResolutionSourceMismatch
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    pool.sync_status(now);
+    require!(
+        now >= pool.close_ts,
+        TimeTravelerError::PoolStillActive
+    );
+
+    pool.apply_resolution(resolution)?;
+    pool.status = PoolStatus::Proposed;
+    pool.dispute_deadline = now
+        .checked_add(registry.dispute_window_secs as i64)
+        .ok_or(TimeTravelerError::NumericalOverflow)?;
+
+    emit!(OutcomeProposed {
+        pool: pool.key(),
+        winning_outcome: pool.winning_outcome,
+        resolved_value: pool.resolved_value,
+        dispute_deadline: pool.dispute_deadline,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeOutcome<'info> {
+    /// Registry authority or a Resolver proposing the outcome
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = registry.has_role(authority.key, roles.as_deref(), Role::Resolver) @ TimeTravelerError::Unauthorized,
+        constraint = !registry.paused @ TimeTravelerError::ProgramPaused
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// Role table; only needed when the signer is not the registry authority
+    #[account(
+        seeds = [b"roles", registry.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Option<Account<'info, Roles>>,
+
+    /// The pool being resolved
+    #[account(
+        mut,
+        has_one = registry @ TimeTravelerError::InvalidRegistryReference,
+        constraint = !pool.paused @ TimeTravelerError::PoolPaused
+    )]
+    pub pool: Account<'info, Pool>,
+}
+// propose_outcome instruction
@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Return a contributor's stake from a cancelled pool.
+/// Early-exit penalties left in the pool are shared pro-rata among the
+/// remaining positions. No protocol fee is taken on refunds.
+/// Native-SOL pools refund in lamports.
+pub fn handler(ctx: Context<Refund>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let contrib = &mut ctx.accounts.contribution;
+
+    require!(
+        pool.status == PoolStatus::Cancelled,
+        TimeTravelerError::PoolNotCancelled
+    );
+    require!(!contrib.claimed, TimeTravelerError::AlreadyClaimed);
+    require!(contrib.amount > 0, TimeTravelerError::NothingToClaim);
+
+    let amount = pro_rata(contrib.amount, pool.total_contributed, pool.staked_total()?)?;
+
+    // Mark refunded before moving funds
+    contrib.claimed = true;
+    let settled = pool.settle_position(contrib.amount)?;
+
+    // Transfer stake from pool vault -> contributor, signed by pool PDA
+    let pool_seeds = pool.signer_seeds();
+    let signer_seeds: &[&[&[u8]]] = &[&pool_seeds];
+    if pool.native_sol {
+        // Unwrap to lamports through a temporary wSOL account
+        let unwrap_account = ctx
+            .accounts
+            .unwrap_account
+            .as_ref()
+            .ok_or(TimeTravelerError::TokenAccountMissing)?;
+        unwrap_sol(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.pool_vault.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            unwrap_account.to_account_info(),
+            pool.to_account_info(),
+            ctx.accounts.contributor.to_account_info(),
+            signer_seeds,
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+    } else {
+        let user_token_account = ctx
+            .accounts
+            .user_token_account
+            .as_ref()
+            .ok_or(TimeTravelerError::TokenAccountMissing)?;
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.pool_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: user_token_account.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+    }
+
+    emit!(Refunded {
+        pool: pool.key(),
+        user: contrib.user,
+        amount,
+    });
+
+    if settled {
+        emit!(PoolSettled { pool: pool.key() });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Refund<'info> {
+    /// Contributor reclaiming their stake
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    /// Global registry (pause switch)
+    #[account(
+        constraint = !registry.paused @ TimeTravelerError::ProgramPaused
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// The cancelled pool
+    #[account(
+        mut,
+        has_one = registry @ TimeTravelerError::InvalidRegistryReference,
+        constraint = !pool.paused @ TimeTravelerError::PoolPaused,
+        constraint = pool.is_pda(&pool.key()) @ TimeTravelerError::InvalidPoolAddress
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Contributor’s Contribution PDA
+    #[account(
+        mut,
+        seeds = [b"contrib", pool.key().as_ref(), contributor.key().as_ref()],
+        bump,
+        constraint = contribution.pool == pool.key() @ TimeTravelerError::InvalidContribution
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    /// Pool vault holding all contributions
+    #[account(
+        mut,
+        seeds = [b"vault", pool.key().as_ref()],
+        bump,
+        constraint = pool_vault.owner == pool.key() @ TimeTravelerError::InvalidVaultOwner,
+        constraint = pool_vault.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Contributor’s token account receiving the refund; not used by
+    /// native-SOL pools, which pay out lamports
+    #[account(
+        mut,
+        constraint = user_token_account.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub user_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Mint of the pool, needed for checked transfers
+    #[account(address = pool.mint @ TimeTravelerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Temporary wSOL account for native-SOL pools, closed to the contributor
+    /// within the instruction so the payout arrives as lamports
+    #[account(
+        init,
+        payer = contributor,
+        token::mint = mint,
+        token::authority = pool,
+        token::token_program = token_program,
+        seeds = [b"unwrap", pool.key().as_ref(), contributor.key().as_ref()],
+        bump
+    )]
+    pub unwrap_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+// refund instruction
@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Release a community pool's creation bond once the pool is resolved or
+/// cancelled. The bond goes back to the creator, or to the registry treasury
+/// if the pool was cancelled as invalid. Native-SOL pools return it to the
+/// creator as lamports, unwrapped through a temporary account funded by
+/// `payer`, who gets its rent back. Callable by anyone.
+pub fn handler(ctx: Context<ReleaseCreationBond>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    require!(pool.community, TimeTravelerError::NotCommunityPool);
+    require!(
+        matches!(
+            pool.status,
+            PoolStatus::Resolved | PoolStatus::Cancelled | PoolStatus::Settled
+        ),
+        TimeTravelerError::PoolNotClosed
+    );
+
+    let amount = pool.creation_bond;
+    require!(amount > 0, TimeTravelerError::NothingToClaim);
+
+    // Clear the bond before moving funds
+    pool.creation_bond = 0;
+    let slashed = pool.invalid;
+
+    // Transfer bond from pool vault, signed by pool PDA
+    let pool_seeds = pool.signer_seeds();
+    let signer_seeds: &[&[&[u8]]] = &[&pool_seeds];
+    if slashed {
+        let balance_before = ctx.accounts.treasury.amount;
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.pool_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.treasury.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+        // Count what arrived, net of any mint transfer fee
+        ctx.accounts.treasury.reload()?;
+        let received = safe_sub(ctx.accounts.treasury.amount, balance_before)?;
+        let stats = &mut ctx.accounts.treasury_stats;
+        stats.fees_collected = safe_add(stats.fees_collected, received)?;
+    } else if pool.native_sol {
+        // Unwrap to lamports through a temporary wSOL account
+        let creator = ctx
+            .accounts
+            .creator
+            .as_ref()
+            .ok_or(TimeTravelerError::TokenAccountMissing)?;
+        let unwrap_account = ctx
+            .accounts
+            .unwrap_account
+            .as_ref()
+            .ok_or(TimeTravelerError::TokenAccountMissing)?;
+        unwrap_sol_to(
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.pool_vault.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            unwrap_account.to_account_info(),
+            pool.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            creator.to_account_info(),
+            signer_seeds,
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+    } else {
+        let creator_token_account = ctx
+            .accounts
+            .creator_token_account
+            .as_ref()
+            .ok_or(TimeTravelerError::TokenAccountMissing)?;
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.pool_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: creator_token_account.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+    }
+
+    emit!(CreationBondReleased {
+        pool: pool.key(),
+        creator: pool.authority,
+        amount,
+        slashed,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReleaseCreationBond<'info> {
+    /// Caller, funds the temporary unwrap account of native-SOL pools
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Global registry
+    pub registry: Account<'info, Registry>,
+
+    /// The community pool
+    #[account(
+        mut,
+        has_one = registry @ TimeTravelerError::InvalidRegistryReference,
+        constraint = pool.is_pda(&pool.key()) @ TimeTravelerError::InvalidPoolAddress
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Pool vault holding the bond
+    #[account(
+        mut,
+        seeds = [b"vault", pool.key().as_ref()],
+        bump,
+        constraint = pool_vault.owner == pool.key() @ TimeTravelerError::InvalidVaultOwner,
+        constraint = pool_vault.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Creator’s token account, receives the bond after a clean resolution;
+    /// not used by native-SOL pools, which pay out lamports
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == pool.authority @ TimeTravelerError::Unauthorized,
+        constraint = creator_token_account.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Creator receiving a native-SOL bond as lamports
+    /// CHECK: must be the pool authority
+    #[account(
+        mut,
+        address = pool.authority @ TimeTravelerError::Unauthorized
+    )]
+    pub creator: Option<UncheckedAccount<'info>>,
+
+    /// Registry treasury for the pool mint, receives a slashed bond
+    #[account(
+        mut,
+        seeds = [b"treasury", registry.key().as_ref(), pool.mint.as_ref()],
+        bump
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    /// Fee totals for the treasury
+    #[account(
+        mut,
+        seeds = [b"treasury_stats", registry.key().as_ref(), pool.mint.as_ref()],
+        bump = treasury_stats.bump
+    )]
+    pub treasury_stats: Account<'info, TreasuryStats>,
+
+    /// Mint of the pool, needed for checked transfers
+    #[account(address = pool.mint @ TimeTravelerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Temporary wSOL account for native-SOL pools, closed to the payer
+    /// within the instruction so the bond reaches the creator as lamports
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint,
+        token::authority = pool,
+        token::token_program = token_program,
+        seeds = [b"unwrap", pool.key().as_ref(), payer.key().as_ref()],
+        bump
+    )]
+    pub unwrap_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+// release_creation_bond instruction
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Update configurable parameters of the global Registry.
+/// Callable by the registry authority or a ConfigAdmin.
+pub fn handler(ctx: Context<SetConfig>, new_config: ConfigParams) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    // Basic validation of parameters
+    require!(
+        new_config.fee_bps as u64 <= BPS_DENOMINATOR,
+        TimeTravelerError::InvalidConfig
+    );
+    require!(
+        new_config.max_price_age_secs > 0
+            && new_config.max_conf_bps as u64 <= BPS_DENOMINATOR
+            && new_config.max_deviation_bps as u64 <= BPS_DENOMINATOR
+            && new_config.referral_bps as u64 <= BPS_DENOMINATOR
+            && new_config.dispute_window_secs > 0
+            && new_config.arbitration_window_secs > 0
+            && new_config.early_exit_penalty_bps as u64 <= BPS_DENOMINATOR
+            && new_config.default_limits.is_valid()
+            && new_config.fee_bps as u64 + new_config.creator_fee_bps as u64 <= BPS_DENOMINATOR,
+        TimeTravelerError::InvalidConfig
+    );
+    // Disputes must cost something once an arbiter can be called in
+    require!(
+        new_config.arbiter == Pubkey::default() || new_config.dispute_bond > 0,
+        TimeTravelerError::InvalidConfig
+    );
+
+    registry.fee_bps = new_config.fee_bps;
+    registry.oracle = new_config.oracle;
+    registry.oracle_type = new_config.oracle_type;
+    registry.secondary_oracle = new_config.secondary_oracle;
+    registry.secondary_oracle_type = new_config.secondary_oracle_type;
+    registry.max_price_age_secs = new_config.max_price_age_secs;
+    registry.max_conf_bps = new_config.max_conf_bps;
+    registry.max_deviation_bps = new_config.max_deviation_bps;
+    registry.referral_bps = new_config.referral_bps;
+    registry.dispute_window_secs = new_config.dispute_window_secs;
+    registry.dispute_bond = new_config.dispute_bond;
+    registry.arbiter = new_config.arbiter;
+    registry.arbitration_window_secs = new_config.arbitration_window_secs;
+    registry.early_exit_penalty_bps = new_config.early_exit_penalty_bps;
+    registry.exit_lockout_secs = new_config.exit_lockout_secs;
+    registry.default_limits = new_config.default_limits;
+    registry.default_lock_period_secs = new_config.default_lock_period_secs;
+    registry.community_bond = new_config.community_bond;
+    registry.creator_fee_bps = new_config.creator_fee_bps;
+    registry.timeline_program = new_config.timeline_program;
+    registry.close_grace_secs = new_config.close_grace_secs;
+    registry.config_version = safe_add(registry.config_version, 1)?;
+
+    emit!(ConfigUpdated {
+        registry: registry.key(),
+        fee_bps: registry.fee_bps,
+        oracle: registry.oracle,
+        oracle_type: registry.oracle_type,
+        secondary_oracle: registry.secondary_oracle,
+        config_version: registry.config_version,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetConfig<'info> {
+    /// Registry authority or a ConfigAdmin
+    pub authority: Signer<'info>,
+
+    /// Registry account (must match authority)
+    #[account(
+        mut,
+        constraint = registry.has_role(authority.key, roles.as_deref(), Role::ConfigAdmin) @ TimeTravelerError::Unauthorized
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// Role table; only needed when the signer is not the registry authority
+    #[account(
+        seeds = [b"roles", registry.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Option<Account<'info, Roles>>,
+}
+
+/// Parameters that can be updated by authority
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigParams {
+    /// Fee in basis points (max 10000 = 100%)
+    pub fee_bps: u16,
+    /// Oracle public key (Pyth price account or Switchboard on-demand pull feed)
+    pub oracle: Pubkey,
+    /// Provider of the oracle account
+    pub oracle_type: OracleType,
+    /// Second feed the primary price must agree with (default pubkey = none)
+    pub secondary_oracle: Pubkey,
+    /// Provider of the secondary oracle account
+    pub secondary_oracle_type: OracleType,
+    /// Maximum gap, in seconds, between an oracle price publish time and pool close_ts (either side)
+    pub max_price_age_secs: u32,
+    /// Maximum confidence interval as bps of the price
+    pub max_conf_bps: u16,
+    /// Maximum disagreement between primary and secondary feeds, in bps
+    pub max_deviation_bps: u16,
+    /// Share of the protocol fee credited to referrers, in bps
+    pub referral_bps: u16,
+    /// Seconds a proposed outcome stays open to disputes
+    pub dispute_window_secs: u32,
+    /// Bond a disputer posts, in base units of the pool mint (must be > 0 when an arbiter is set)
+    pub dispute_bond: u64,
+    /// Key that settles disputes (default pubkey = disputes disabled)
+    pub arbiter: Pubkey,
+    /// Seconds the arbiter has to settle a dispute before anyone can cancel the pool
+    pub arbitration_window_secs: u32,
+    /// Share of an early withdrawal kept in the pool, in bps
+    pub early_exit_penalty_bps: u16,
+    /// Seconds before pool close_ts after which early exits are refused
+    pub exit_lockout_secs: u32,
+    /// Contribution limits for pools created without overrides
+    pub default_limits: ContributionLimits,
+    /// Seconds before close_ts during which pools refuse contributions
+    pub default_lock_period_secs: u32,
+    /// Bond a community pool creator locks, in base units of the pool mint
+    pub community_bond: u64,
+    /// Community pool creator's cut of each claim, in bps (fee_bps + creator_fee_bps <= 10000)
+    pub creator_fee_bps: u16,
+    /// Timeline archive program to CPI into on pool close (default = none).
+    /// The archive's ArchiveWriter PDA must name the registry PDA as its writer.
+    pub timeline_program: Pubkey,
+    /// Seconds after close_ts before a settled pool's accounts can be closed
+    pub close_grace_secs: u32,
+}
+// set_config instruction
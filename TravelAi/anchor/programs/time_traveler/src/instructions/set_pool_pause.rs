@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Pause or resume a single pool, freezing its contributions and settlement.
+/// `reason` is an operator-defined code carried in the event for monitoring.
+/// Callable by the registry authority or a Pauser.
+pub fn handler(ctx: Context<SetPoolPause>, paused: bool, reason: u16) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    pool.paused = paused;
+
+    emit!(PoolPauseSet {
+        pool: pool.key(),
+        pauser: ctx.accounts.pauser.key(),
+        paused,
+        reason,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPoolPause<'info> {
+    /// Registry authority or a Pauser
+    pub pauser: Signer<'info>,
+
+    #[account(
+        constraint = registry.has_role(pauser.key, roles.as_deref(), Role::Pauser) @ TimeTravelerError::Unauthorized
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// Role table; only needed when the signer is not the registry authority
+    #[account(
+        seeds = [b"roles", registry.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Option<Account<'info, Roles>>,
+
+    /// The pool being paused or resumed
+    #[account(
+        mut,
+        has_one = registry @ TimeTravelerError::InvalidRegistryReference
+    )]
+    pub pool: Account<'info, Pool>,
+}
+// set_pool_pause instruction
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Pause or resume the whole program. While paused no pool can be created,
+/// contributed to or settled. `reason` is an operator-defined code carried
+/// in the event for monitoring.
+/// Callable by the registry authority or a Pauser.
+pub fn handler(ctx: Context<SetRegistryPause>, paused: bool, reason: u16) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    registry.paused = paused;
+
+    emit!(RegistryPauseSet {
+        registry: registry.key(),
+        pauser: ctx.accounts.pauser.key(),
+        paused,
+        reason,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRegistryPause<'info> {
+    /// Registry authority or a Pauser
+    pub pauser: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = registry.has_role(pauser.key, roles.as_deref(), Role::Pauser) @ TimeTravelerError::Unauthorized
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// Role table; only needed when the signer is not the registry authority
+    #[account(
+        seeds = [b"roles", registry.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Option<Account<'info, Roles>>,
+}
+// set_registry_pause instruction
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::events::*;
+
+/// Bring a pool's stored status in line with the clock
+/// (Scheduled -> Open -> Locked) so indexers see the current phase.
+/// Callable by anyone.
+pub fn handler(ctx: Context<SyncPoolStatus>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    let previous = pool.status;
+    let status = pool.sync_status(Clock::get()?.unix_timestamp);
+
+    if status != previous {
+        emit!(PoolStatusSynced {
+            pool: pool.key(),
+            status,
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SyncPoolStatus<'info> {
+    /// The pool being synced
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+}
+// sync_pool_status instruction
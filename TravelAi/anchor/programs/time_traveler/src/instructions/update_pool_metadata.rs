@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Replace the descriptive metadata of a pool.
+/// Only allowed while the pool is Scheduled or Open, so the question and
+/// resolution criteria cannot change once contributions are locked in.
+/// Callable by the pool authority.
+pub fn handler(ctx: Context<UpdatePoolMetadata>, metadata: PoolMetadataParams) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    require!(
+        matches!(
+            pool.sync_status(Clock::get()?.unix_timestamp),
+            PoolStatus::Scheduled | PoolStatus::Open
+        ),
+        TimeTravelerError::PoolLocked
+    );
+
+    ctx.accounts.metadata.apply(metadata)?;
+
+    emit!(PoolMetadataUpdated {
+        pool: pool.key(),
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdatePoolMetadata<'info> {
+    /// Authority (creator) of the pool
+    pub authority: Signer<'info>,
+
+    /// The pool being described
+    #[account(
+        mut,
+        has_one = authority @ TimeTravelerError::Unauthorized
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// The pool's metadata PDA
+    #[account(
+        mut,
+        seeds = [b"metadata", pool.key().as_ref()],
+        bump = metadata.bump
+    )]
+    pub metadata: Account<'info, PoolMetadata>,
+}
+// update_pool_metadata instruction
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Verify the outcome of an oracle-sourced pool and close it.
+/// Pools must pass `Resolution::Oracle`: the result is derived from the
+/// registry price feed rather than supplied by the signer. Binary categorical
+/// pools resolve to a bucket index; range and scalar pools to the price.
+/// Authority-sourced pools resolve through `propose_outcome` instead.
+/// Pools linked to a timeline prediction mirror the result into it through
+/// `mirror_pool_result`.
+/// Callable by the registry authority or a Resolver.
+pub fn handler(ctx: Context<VerifyAndClosePool>, resolution: Resolution) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    // Ensure pool is unresolved and past close_ts
+    require!(pool.is_unresolved(), TimeTravelerError::PoolAlreadyClosed);
+
+    let now = Clock::get()?.unix_timestamp;
+    pool.sync_status(now);
+    require!(
+        now >= pool.close_ts,
+        TimeTravelerError::PoolStillActive
+    );
+
+    // Derive the result on-chain for oracle-sourced pools
+    let resolution = match (pool.resolution_source, resolution) {
+        (ResolutionSource::Oracle { expo, target }, Resolution::Oracle) => {
+            let oracle_feed = ctx
+                .accounts
+                .oracle_feed
+                .as_ref()
+                .ok_or(TimeTravelerError::OracleFeedMissing)?;
+            let registry = &ctx.accounts.registry;
+            let price = read_oracle_price(registry.oracle_type, oracle_feed)?;
+            check_price_age(&price, pool.close_ts, registry.max_price_age_secs)?;
+            check_confidence(&price, registry.max_conf_bps)?;
+
+            // Cross-check against the secondary feed when one is configured
+            if registry.secondary_oracle != Pubkey::default() {
+                let secondary_feed = ctx
+                    .accounts
+                    .secondary_oracle_feed
+                    .as_ref()
+                    .ok_or(TimeTravelerError::OracleFeedMissing)?;
+                let secondary = read_oracle_price(registry.secondary_oracle_type, secondary_feed)?;
+                check_price_age(&secondary, pool.close_ts, registry.max_price_age_secs)?;
+                check_confidence(&secondary, registry.max_conf_bps)?;
+                check_deviation(&price, &secondary, registry.max_deviation_bps)?;
+            }
+
+            let value = price
+                .scaled_to(expo)
+                .ok_or(TimeTravelerError::NumericalOverflow)?;
+            match pool.kind {
+                PoolKind::Categorical => Resolution::Outcome(if value >= target { 0 } else { 1 }),
+                PoolKind::Range { .. } | PoolKind::Scalar { .. } => Resolution::Value(value),
+            }
+        }
+        _ => return err!(TimeTravelerError::ResolutionSourceMismatch),
+    };
+
+    // Record verified outcome
+    pool.apply_resolution(resolution)?;
+    pool.mark_resolved()?;
+
+    // Mirror the result into the timeline archive
+    mirror_pool_result(
+        pool,
+        &ctx.accounts.registry,
+        ctx.accounts.timeline_program.as_ref(),
+        ctx.accounts.timeline_archive.as_ref(),
+        ctx.accounts.timeline_writer.as_ref(),
+        ctx.accounts.timeline_prediction.as_ref(),
+    )?;
+
+    // Emit event for off-chain indexers
+    emit!(PoolClosed {
+        pool: pool.key(),
+        signal_id: pool.signal_id,
+        winning_outcome: pool.winning_outcome,
+        resolved_value: pool.resolved_value,
+        total_contributed: pool.total_contributed,
+        refunded: pool.status != PoolStatus::Resolved,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct VerifyAndClosePool<'info> {
+    /// Registry authority or a Resolver
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = registry.has_role(authority.key, roles.as_deref(), Role::Resolver) @ TimeTravelerError::Unauthorized,
+        constraint = !registry.paused @ TimeTravelerError::ProgramPaused
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// Role table; only needed when the signer is not the registry authority
+    #[account(
+        seeds = [b"roles", registry.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Option<Account<'info, Roles>>,
+
+    /// The pool being verified and closed
+    #[account(
+        mut,
+        has_one = registry @ TimeTravelerError::InvalidRegistryReference,
+        constraint = !pool.paused @ TimeTravelerError::PoolPaused
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Vault holding all contributions (remains locked until settlement)
+    #[account(
+        mut,
+        constraint = pool_vault.owner == pool.key() @ TimeTravelerError::InvalidVaultOwner,
+        constraint = pool_vault.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Pyth or Switchboard account; must be the feed configured on the registry.
+    /// Only required for oracle-sourced pools. Parsed in `read_oracle_price`.
+    #[account(
+        constraint = oracle_feed.key() == registry.oracle @ TimeTravelerError::InvalidOracleAccount
+    )]
+    pub oracle_feed: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Secondary feed configured on the registry, used to cross-check
+    /// the primary price. Parsed in `read_oracle_price`.
+    #[account(
+        constraint = secondary_oracle_feed.key() == registry.secondary_oracle @ TimeTravelerError::InvalidOracleAccount
+    )]
+    pub secondary_oracle_feed: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Timeline program configured on the registry; only required for
+    /// pools linked to a timeline prediction.
+    #[account(
+        executable,
+        constraint = timeline_program.key() == registry.timeline_program @ TimeTravelerError::InvalidTimelineAccount
+    )]
+    pub timeline_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Timeline Archive PDA; address checked in `mirror_pool_result`
+    /// and validated by the timeline program.
+    pub timeline_archive: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Timeline ArchiveWriter PDA naming the registry PDA as writer;
+    /// address checked in `mirror_pool_result` and validated by the timeline program.
+    pub timeline_writer: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Timeline PredictionRecord PDA for `pool.timeline_prediction_id`;
+    /// address checked in `mirror_pool_result` and validated by the timeline program.
+    #[account(mut)]
+    pub timeline_prediction: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+// verify_and_close_pool instruction
@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Withdraw part or all of a position from an open pool before it locks.
+/// Exits are allowed until `registry.exit_lockout_secs` before `close_ts`.
+/// `registry.early_exit_penalty_bps` of the amount (rounded up) is kept in
+/// the pool: it leaves the outcome bucket but stays in `total_contributed`,
+/// so it is shared by the remaining participants at settlement.
+pub fn handler(ctx: Context<WithdrawContribution>, amount: u64) -> Result<()> {
+    let registry = &ctx.accounts.registry;
+    let pool = &mut ctx.accounts.pool;
+    let contrib = &mut ctx.accounts.contribution;
+
+    require!(amount > 0, TimeTravelerError::InvalidAmount);
+    require!(
+        pool.sync_status(Clock::get()?.unix_timestamp) == PoolStatus::Open,
+        TimeTravelerError::PoolClosed
+    );
+    require!(amount <= contrib.amount, TimeTravelerError::InvalidAmount);
+
+    let lockout_start = pool
+        .close_ts
+        .checked_sub(registry.exit_lockout_secs as i64)
+        .ok_or(TimeTravelerError::NumericalOverflow)?;
+    require!(
+        Clock::get()?.unix_timestamp < lockout_start,
+        TimeTravelerError::ExitLockedOut
+    );
+
+    let penalty = apply_bps(amount, registry.early_exit_penalty_bps, Rounding::Up)?;
+    let returned = safe_sub(amount, penalty)?;
+
+    // Update position and pool totals before moving funds
+    contrib.amount = safe_sub(contrib.amount, amount)?;
+    if contrib.amount == 0 {
+        pool.contributors = safe_sub(pool.contributors, 1)?;
+    }
+    let bucket = &mut pool.buckets[contrib.outcome as usize];
+    bucket.total = safe_sub(bucket.total, amount)?;
+    pool.total_contributed = safe_sub(pool.total_contributed, returned)?;
+
+    // Transfer stake minus penalty from pool vault -> contributor, signed by pool PDA
+    if returned > 0 {
+        let pool_seeds = pool.signer_seeds();
+        let signer_seeds: &[&[&[u8]]] = &[&pool_seeds];
+        if pool.native_sol {
+            // Unwrap to lamports through a temporary wSOL account
+            let unwrap_account = ctx
+                .accounts
+                .unwrap_account
+                .as_ref()
+                .ok_or(TimeTravelerError::TokenAccountMissing)?;
+            unwrap_sol(
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.pool_vault.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                unwrap_account.to_account_info(),
+                pool.to_account_info(),
+                ctx.accounts.contributor.to_account_info(),
+                signer_seeds,
+                returned,
+                ctx.accounts.mint.decimals,
+            )?;
+        } else {
+            let user_token_account = ctx
+                .accounts
+                .user_token_account
+                .as_ref()
+                .ok_or(TimeTravelerError::TokenAccountMissing)?;
+            let cpi_accounts = TransferChecked {
+                from: ctx.accounts.pool_vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: user_token_account.to_account_info(),
+                authority: pool.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token_interface::transfer_checked(cpi_ctx, returned, ctx.accounts.mint.decimals)?;
+        }
+    }
+
+    emit!(ContributionWithdrawn {
+        pool: pool.key(),
+        user: contrib.user,
+        outcome: contrib.outcome,
+        amount: returned,
+        penalty,
+        total_contributed: pool.total_contributed,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawContribution<'info> {
+    /// Contributor exiting their position
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    /// Global registry (exit penalty and lock-out)
+    #[account(
+        constraint = !registry.paused @ TimeTravelerError::ProgramPaused
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// The open pool
+    #[account(
+        mut,
+        has_one = registry @ TimeTravelerError::InvalidRegistryReference,
+        constraint = !pool.paused @ TimeTravelerError::PoolPaused,
+        constraint = pool.is_pda(&pool.key()) @ TimeTravelerError::InvalidPoolAddress
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Contributor’s Contribution PDA
+    #[account(
+        mut,
+        seeds = [b"contrib", pool.key().as_ref(), contributor.key().as_ref()],
+        bump,
+        constraint = contribution.pool == pool.key() @ TimeTravelerError::InvalidContribution
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    /// Pool vault holding all contributions
+    #[account(
+        mut,
+        seeds = [b"vault", pool.key().as_ref()],
+        bump,
+        constraint = pool_vault.owner == pool.key() @ TimeTravelerError::InvalidVaultOwner,
+        constraint = pool_vault.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Contributor’s token account receiving the stake; not used by
+    /// native-SOL pools, which pay out lamports
+    #[account(
+        mut,
+        constraint = user_token_account.mint == pool.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub user_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Mint of the pool, needed for checked transfers
+    #[account(address = pool.mint @ TimeTravelerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Temporary wSOL account for native-SOL pools, closed to the contributor
+    /// within the instruction so the payout arrives as lamports
+    #[account(
+        init,
+        payer = contributor,
+        token::mint = mint,
+        token::authority = pool,
+        token::token_program = token_program,
+        seeds = [b"unwrap", pool.key().as_ref(), contributor.key().as_ref()],
+        bump
+    )]
+    pub unwrap_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+// withdraw_contribution instruction
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Withdraw collected protocol fees from a treasury. Referral rewards credited
+/// but not yet claimed stay in the treasury.
+/// Callable by the registry authority or a FeeManager.
+pub fn handler(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+    require!(amount > 0, TimeTravelerError::InvalidAmount);
+
+    let registry = &ctx.accounts.registry;
+
+    let available = ctx
+        .accounts
+        .treasury
+        .amount
+        .saturating_sub(ctx.accounts.treasury_stats.referral_rewards_owed()?);
+    require!(amount <= available, TimeTravelerError::TreasuryReserved);
+
+    // Transfer fees from treasury -> destination, signed by registry PDA
+    let signer_seeds: &[&[&[u8]]] = &[&[b"registry", &[registry.bump]]];
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.treasury.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.destination.to_account_info(),
+        authority: registry.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+    let stats = &mut ctx.accounts.treasury_stats;
+    stats.fees_withdrawn = safe_add(stats.fees_withdrawn, amount)?;
+
+    emit!(FeesWithdrawn {
+        registry: registry.key(),
+        mint: stats.mint,
+        destination: ctx.accounts.destination.key(),
+        amount,
+        fees_withdrawn: stats.fees_withdrawn,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    /// Registry authority or a FeeManager
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = registry.has_role(authority.key, roles.as_deref(), Role::FeeManager) @ TimeTravelerError::Unauthorized,
+        seeds = [b"registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, Registry>,
+
+    /// Role table; only needed when the signer is not the registry authority
+    #[account(
+        seeds = [b"roles", registry.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Option<Account<'info, Roles>>,
+
+    /// Treasury PDA holding collected fees
+    #[account(
+        mut,
+        seeds = [b"treasury", registry.key().as_ref(), treasury.mint.as_ref()],
+        bump
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    /// Fee totals for the treasury
+    #[account(
+        mut,
+        seeds = [b"treasury_stats", registry.key().as_ref(), treasury.mint.as_ref()],
+        bump = treasury_stats.bump
+    )]
+    pub treasury_stats: Account<'info, TreasuryStats>,
+
+    /// Token account receiving the fees
+    #[account(
+        mut,
+        constraint = destination.mint == treasury.mint @ TimeTravelerError::InvalidMint
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    /// Mint of the treasury, needed for checked transfers
+    #[account(address = treasury.mint @ TimeTravelerError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+// withdraw_fees instruction
@@ -1 +1,207 @@
+#![allow(clippy::result_large_err)]
+
+use anchor_lang::prelude::*;
+
+pub mod errors;
+pub mod events;
+pub mod instructions;
+pub mod state;
+pub mod utils;
+
+use instructions::*;
+use state::*;
+
+// Replace with your actual program ID (anchor keys list)
+declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[program]
+pub mod time_traveler {
+    use super::*;
+
+    /// Initialize the global Registry (once).
+    pub fn init_registry(ctx: Context<InitRegistry>, authority: Pubkey) -> Result<()> {
+        instructions::init_registry::handler(ctx, authority)
+    }
+
+    /// Nominate a new Registry authority.
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::propose_authority::handler(ctx, new_authority)
+    }
+
+    /// Take over the Registry as the nominated authority.
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+
+    /// Withdraw a pending authority nomination.
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        instructions::cancel_authority_transfer::handler(ctx)
+    }
+
+    /// Update fee and oracle configuration.
+    pub fn set_config(ctx: Context<SetConfig>, new_config: ConfigParams) -> Result<()> {
+        instructions::set_config::handler(ctx, new_config)
+    }
+
+    /// Create the role table of the Registry.
+    pub fn init_roles(ctx: Context<InitRoles>) -> Result<()> {
+        instructions::init_roles::handler(ctx)
+    }
+
+    /// Grant a role to a key.
+    pub fn grant_role(ctx: Context<GrantRole>, member: Pubkey, role: Role) -> Result<()> {
+        instructions::grant_role::handler(ctx, member, role)
+    }
+
+    /// Revoke a role from a key.
+    pub fn revoke_role(ctx: Context<RevokeRole>, member: Pubkey, role: Role) -> Result<()> {
+        instructions::revoke_role::handler(ctx, member, role)
+    }
+
+    /// Pause or resume the whole program.
+    pub fn set_registry_pause(ctx: Context<SetRegistryPause>, paused: bool, reason: u16) -> Result<()> {
+        instructions::set_registry_pause::handler(ctx, paused, reason)
+    }
+
+    /// Pause or resume a single pool.
+    pub fn set_pool_pause(ctx: Context<SetPoolPause>, paused: bool, reason: u16) -> Result<()> {
+        instructions::set_pool_pause::handler(ctx, paused, reason)
+    }
+
+    /// Open a prediction pool for an AI signal with one bucket per outcome.
+    pub fn create_pool(
+        ctx: Context<CreatePool>,
+        signal_id: [u8; 32],
+        params: PoolParams,
+        metadata: PoolMetadataParams,
+    ) -> Result<()> {
+        instructions::create_pool::handler(ctx, signal_id, params, metadata)
+    }
+
+    /// Open a community pool by locking the registry creation bond.
+    pub fn create_community_pool(
+        ctx: Context<CreateCommunityPool>,
+        signal_id: [u8; 32],
+        params: PoolParams,
+        metadata: PoolMetadataParams,
+    ) -> Result<()> {
+        instructions::create_community_pool::handler(ctx, signal_id, params, metadata)
+    }
+
+    /// Return or slash a community pool's creation bond.
+    pub fn release_creation_bond(ctx: Context<ReleaseCreationBond>) -> Result<()> {
+        instructions::release_creation_bond::handler(ctx)
+    }
+
+    /// Collect a community pool creator's accrued fees.
+    pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+        instructions::claim_creator_fees::handler(ctx)
+    }
+
+    /// Edit a pool's metadata until the pool locks.
+    pub fn update_pool_metadata(
+        ctx: Context<UpdatePoolMetadata>,
+        metadata: PoolMetadataParams,
+    ) -> Result<()> {
+        instructions::update_pool_metadata::handler(ctx, metadata)
+    }
+
+    /// Move a pool to its clock-driven phase.
+    pub fn sync_pool_status(ctx: Context<SyncPoolStatus>) -> Result<()> {
+        instructions::sync_pool_status::handler(ctx)
+    }
+
+    /// Contribute tokens to one outcome of an open pool, optionally naming a referrer.
+    pub fn contribute(
+        ctx: Context<Contribute>,
+        amount: u64,
+        outcome: u8,
+        referrer: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::contribute::handler(ctx, amount, outcome, referrer)
+    }
+
+    /// Exit part of a position before the pool locks, minus the early-exit penalty.
+    pub fn withdraw_contribution(ctx: Context<WithdrawContribution>, amount: u64) -> Result<()> {
+        instructions::withdraw_contribution::handler(ctx, amount)
+    }
+
+    /// Resolve an oracle-sourced pool from the price feed and close it.
+    pub fn verify_and_close_pool(
+        ctx: Context<VerifyAndClosePool>,
+        resolution: Resolution,
+    ) -> Result<()> {
+        instructions::verify_and_close_pool::handler(ctx, resolution)
+    }
+
+    /// Propose the outcome of a closed authority-sourced pool.
+    pub fn propose_outcome(ctx: Context<ProposeOutcome>, resolution: Resolution) -> Result<()> {
+        instructions::propose_outcome::handler(ctx, resolution)
+    }
+
+    /// Dispute a proposed outcome by posting a bond.
+    pub fn dispute_outcome(ctx: Context<DisputeOutcome>) -> Result<()> {
+        instructions::dispute_outcome::handler(ctx)
+    }
+
+    /// Finalize an undisputed proposal after the dispute window.
+    pub fn finalize_outcome(ctx: Context<FinalizeOutcome>) -> Result<()> {
+        instructions::finalize_outcome::handler(ctx)
+    }
+
+    /// Settle a disputed proposal (arbiter only).
+    pub fn arbitrate(ctx: Context<Arbitrate>, resolution: Resolution) -> Result<()> {
+        instructions::arbitrate::handler(ctx, resolution)
+    }
+
+    /// Cancel a disputed pool the arbiter did not settle in time, refunding the bond.
+    pub fn expire_dispute(ctx: Context<ExpireDispute>) -> Result<()> {
+        instructions::expire_dispute::handler(ctx)
+    }
+
+    /// Claim a pro-rata share of a resolved pool.
+    pub fn claim(ctx: Context<Claim>) -> Result<()> {
+        instructions::claim::handler(ctx)
+    }
+
+    /// Void an unresolved pool so contributors can be refunded.
+    pub fn cancel_pool(ctx: Context<CancelPool>, invalid: bool) -> Result<()> {
+        instructions::cancel_pool::handler(ctx, invalid)
+    }
+
+    /// Refund a contribution from a cancelled pool.
+    pub fn refund(ctx: Context<Refund>) -> Result<()> {
+        instructions::refund::handler(ctx)
+    }
+
+    /// Close a finished Contribution and return its rent.
+    pub fn close_contribution(ctx: Context<CloseContribution>) -> Result<()> {
+        instructions::close_contribution::handler(ctx)
+    }
+
+    /// Close a settled pool with its vault and metadata, sweeping dust to the treasury.
+    pub fn close_pool(ctx: Context<ClosePool>) -> Result<()> {
+        instructions::close_pool::handler(ctx)
+    }
+
+    /// Create the fee treasury for a mint and its fee totals account.
+    pub fn init_treasury(ctx: Context<InitTreasury>) -> Result<()> {
+        instructions::init_treasury::handler(ctx)
+    }
+
+    /// Withdraw collected protocol fees.
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+        instructions::withdraw_fees::handler(ctx, amount)
+    }
+
+    /// Register as a referrer for a mint.
+    pub fn init_referral(ctx: Context<InitReferral>) -> Result<()> {
+        instructions::init_referral::handler(ctx)
+    }
+
+    /// Claim accrued referral rewards.
+    pub fn claim_referral_rewards(ctx: Context<ClaimReferralRewards>) -> Result<()> {
+        instructions::claim_referral_rewards::handler(ctx)
+    }
+}
 // Anchor entrypoint
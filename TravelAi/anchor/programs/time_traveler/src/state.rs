@@ -1 +1,1077 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TimeTravelerError;
+use crate::utils::{mul_div, pro_rata, safe_add, safe_sub, Rounding};
+
+/// Price feed provider behind `Registry::oracle`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OracleType {
+    /// Pyth v2 price account
+    Pyth,
+    /// Switchboard on-demand pull feed account
+    Switchboard,
+}
+
+/// Global program configuration, created once by `init_registry`.
+/// PDA seeds: ["registry"]
+#[account]
+pub struct Registry {
+    pub authority: Pubkey,   // key allowed to manage pools and config
+    pub pending_authority: Pubkey, // nominated successor (default = none)
+    pub version: u8,         // account layout version
+    pub fee_bps: u16,        // protocol fee in basis points (max 10000)
+    pub oracle: Pubkey,      // price feed used for pool verification
+    pub oracle_type: OracleType,
+    pub secondary_oracle: Pubkey,  // optional cross-check feed (default = none)
+    pub secondary_oracle_type: OracleType,
+    pub max_price_age_secs: u32,   // widest accepted gap between price publish time and pool close_ts
+    pub max_conf_bps: u16,         // widest accepted confidence interval vs price
+    pub max_deviation_bps: u16,    // widest accepted gap between the two feeds
+    pub referral_bps: u16,         // share of the protocol fee credited to referrers
+    pub dispute_window_secs: u32,  // time after a proposal during which it can be disputed
+    pub dispute_bond: u64,         // bond (pool mint base units) posted to dispute
+    pub arbiter: Pubkey,           // settles disputed proposals (default = disputes disabled)
+    pub arbitration_window_secs: u32, // time the arbiter has to settle a dispute before it expires
+    pub early_exit_penalty_bps: u16, // share of an early withdrawal kept in the pool
+    pub exit_lockout_secs: u32,    // early exits close this long before pool close_ts
+    pub default_limits: ContributionLimits, // limits for pools created without overrides
+    pub default_lock_period_secs: u32, // contributions stop this long before close_ts
+    pub community_bond: u64,       // bond (pool mint base units) to create a community pool
+    pub creator_fee_bps: u16,      // community pool creator's cut of each claim
+    pub timeline_program: Pubkey,  // timeline archive updated on pool close (default = none)
+    pub close_grace_secs: u32,     // settled pools can be closed this long after close_ts
+    pub paused: bool,        // blocks pool creation, contributions and settlement
+    pub config_version: u64, // bumped on every set_config
+    pub bump: u8,
+}
+
+impl Registry {
+    pub const SIZE: usize = 32 // authority
+        + 32                   // pending_authority
+        + 1                    // version
+        + 2                    // fee_bps
+        + 32                   // oracle
+        + 1                    // oracle_type (enum)
+        + 32                   // secondary_oracle
+        + 1                    // secondary_oracle_type (enum)
+        + 4                    // max_price_age_secs
+        + 2                    // max_conf_bps
+        + 2                    // max_deviation_bps
+        + 2                    // referral_bps
+        + 4                    // dispute_window_secs
+        + 8                    // dispute_bond
+        + 32                   // arbiter
+        + 4                    // arbitration_window_secs
+        + 2                    // early_exit_penalty_bps
+        + 4                    // exit_lockout_secs
+        + ContributionLimits::SIZE // default_limits
+        + 4                    // default_lock_period_secs
+        + 8                    // community_bond
+        + 2                    // creator_fee_bps
+        + 32                   // timeline_program
+        + 4                    // close_grace_secs
+        + 1                    // paused
+        + 8                    // config_version
+        + 1;                   // bump
+}
+
+impl Registry {
+    /// Whether `key` may act as `role`: the registry authority holds every
+    /// role, other keys need it granted in the `Roles` table.
+    pub fn has_role(&self, key: &Pubkey, roles: Option<&Roles>, role: Role) -> bool {
+        *key == self.authority || matches!(roles, Some(roles) if roles.has_role(key, role))
+    }
+}
+
+/// Anti-dust and anti-whale limits enforced by `contribute`.
+/// A zero cap means unlimited.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct ContributionLimits {
+    pub min_contribution: u64, // smallest single contribution
+    pub max_per_wallet: u64,   // cap on one wallet's cumulative stake in a pool
+    pub pool_cap: u64,         // hard cap on the pool's total_contributed
+}
+
+impl ContributionLimits {
+    pub const SIZE: usize = 8 // min_contribution
+        + 8                   // max_per_wallet
+        + 8;                  // pool_cap
+
+    /// Whether the limits can be satisfied at all.
+    pub fn is_valid(&self) -> bool {
+        (self.max_per_wallet == 0 || self.min_contribution <= self.max_per_wallet)
+            && (self.pool_cap == 0 || self.min_contribution <= self.pool_cap)
+    }
+
+    /// Check a contribution of `amount` that brings the wallet's stake to
+    /// `wallet_total` and the pool to `pool_total`.
+    pub fn check(&self, amount: u64, wallet_total: u64, pool_total: u64) -> Result<()> {
+        require!(
+            amount >= self.min_contribution,
+            TimeTravelerError::ContributionTooSmall
+        );
+        require!(
+            self.max_per_wallet == 0 || wallet_total <= self.max_per_wallet,
+            TimeTravelerError::WalletCapExceeded
+        );
+        require!(
+            self.pool_cap == 0 || pool_total <= self.pool_cap,
+            TimeTravelerError::PoolCapReached
+        );
+        Ok(())
+    }
+}
+
+/// Delegated permissions that can be granted to keys other than the authority.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    /// May create pools
+    PoolCreator,
+    /// May resolve and cancel pools
+    Resolver,
+    /// May pause the registry and pools
+    Pauser,
+    /// May create treasuries and withdraw fees
+    FeeManager,
+    /// May update the registry configuration
+    ConfigAdmin,
+}
+
+impl Role {
+    /// Bit of this role in `RoleMember::roles`.
+    pub fn mask(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// Maximum number of keys the role table can hold.
+pub const MAX_ROLE_MEMBERS: usize = 16;
+
+/// A key and the roles granted to it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct RoleMember {
+    pub key: Pubkey,
+    pub roles: u8, // bitmask of `Role::mask`, 0 = free slot
+}
+
+impl RoleMember {
+    pub const SIZE: usize = 32 // key
+        + 1;                   // roles
+}
+
+/// Role table of a registry, managed by the registry authority.
+/// PDA seeds: ["roles", registry]
+#[account]
+pub struct Roles {
+    pub registry: Pubkey,
+    pub members: [RoleMember; MAX_ROLE_MEMBERS],
+    pub bump: u8,
+}
+
+impl Roles {
+    pub const SIZE: usize = 32 // registry
+        + RoleMember::SIZE * MAX_ROLE_MEMBERS // members
+        + 1;                   // bump
+
+    /// Whether `key` has been granted `role`.
+    pub fn has_role(&self, key: &Pubkey, role: Role) -> bool {
+        self.members
+            .iter()
+            .any(|member| member.roles != 0 && member.key == *key && member.roles & role.mask() != 0)
+    }
+
+    /// Grant `role` to `key`, taking a free slot if the key holds no role yet.
+    pub fn grant(&mut self, key: Pubkey, role: Role) -> Result<()> {
+        if let Some(member) = self
+            .members
+            .iter_mut()
+            .find(|member| member.roles != 0 && member.key == key)
+        {
+            member.roles |= role.mask();
+            return Ok(());
+        }
+        let slot = self
+            .members
+            .iter_mut()
+            .find(|member| member.roles == 0)
+            .ok_or(TimeTravelerError::RoleTableFull)?;
+        *slot = RoleMember {
+            key,
+            roles: role.mask(),
+        };
+        Ok(())
+    }
+
+    /// Revoke `role` from `key`, freeing its slot once no role is left.
+    pub fn revoke(&mut self, key: &Pubkey, role: Role) -> Result<()> {
+        let member = self
+            .members
+            .iter_mut()
+            .find(|member| member.roles & role.mask() != 0 && member.key == *key)
+            .ok_or(TimeTravelerError::RoleNotHeld)?;
+        member.roles &= !role.mask();
+        if member.roles == 0 {
+            *member = RoleMember::default();
+        }
+        Ok(())
+    }
+}
+
+/// Lifecycle of a prediction pool:
+/// Scheduled -> Open -> Locked -> (Proposed -> Disputed ->) Resolved / Cancelled -> Settled.
+/// Scheduled, Open and Locked follow the clock (see `Pool::sync_status`).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PoolStatus {
+    /// Created, contributions not accepted before open_ts
+    Scheduled,
+    /// Accepting contributions until the lock period before close_ts
+    Open,
+    /// No new contributions; awaiting resolution after close_ts
+    Locked,
+    /// Outcome proposed, disputable until the dispute deadline
+    Proposed,
+    /// Proposal challenged, awaiting the arbiter
+    Disputed,
+    /// Outcome final, winners can claim
+    Resolved,
+    /// Voided by the authority, contributions refundable
+    Cancelled,
+    /// Every payout or refund has been made
+    Settled,
+}
+
+/// Maximum number of outcome buckets a pool can declare.
+pub const MAX_OUTCOMES: usize = 8;
+
+/// One possible outcome of a pool and the stake backing it.
+/// Binary YES/NO pools use two buckets: 0 = YES, 1 = NO.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct OutcomeBucket {
+    pub label_hash: [u8; 32], // hash of the off-chain outcome label
+    pub total: u64,           // stake backing this outcome
+}
+
+impl OutcomeBucket {
+    pub const SIZE: usize = 32 // label_hash
+        + 8;                   // total
+}
+
+/// How a pool's buckets map to a result.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PoolKind {
+    /// One bucket per discrete outcome; resolved by bucket index.
+    Categorical,
+    /// Buckets split [lower, upper) into equal-width ranges; resolved by value.
+    /// Values below/above the bounds land in the first/last bucket.
+    Range { lower: i64, upper: i64 },
+    /// Two buckets, 0 = short and 1 = long; resolved by value. Long receives the
+    /// share of the pool given by where the value lands in [lower, upper], short the rest.
+    Scalar { lower: i64, upper: i64 },
+}
+
+impl PoolKind {
+    pub const SIZE: usize = 1 // variant
+        + 8                   // lower
+        + 8;                  // upper
+}
+
+/// Where a pool's result comes from.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ResolutionSource {
+    /// Result supplied by the registry authority
+    Authority,
+    /// Result derived on-chain from the registry oracle price, rescaled to `expo`.
+    /// Binary categorical pools resolve to bucket 0 (YES) when the price is at or
+    /// above `target` and bucket 1 (NO) otherwise; range and scalar pools resolve
+    /// to the price itself.
+    Oracle { expo: i32, target: i64 },
+}
+
+impl ResolutionSource {
+    pub const SIZE: usize = 1 // variant
+        + 4                   // expo
+        + 8;                  // target
+}
+
+/// Result submitted when verifying a pool.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Resolution {
+    /// Winning bucket index (categorical pools)
+    Outcome(u8),
+    /// Verified numeric value (range and scalar pools)
+    Value(i64),
+    /// Read the result from the oracle feed (oracle-sourced pools)
+    Oracle,
+}
+
+/// Seed prefix of official pool PDAs
+pub const POOL_SEED: &[u8] = b"pool";
+/// Seed prefix of community pool PDAs, which are namespaced by creator
+pub const COMMUNITY_POOL_SEED: &[u8] = b"community_pool";
+
+/// A prediction pool for a single AI signal.
+/// PDA seeds: ["pool", signal_id], or ["community_pool", creator, signal_id]
+/// for community pools
+#[account]
+pub struct Pool {
+    pub registry: Pubkey,                         // Registry this pool belongs to
+    pub authority: Pubkey,                        // authority that created the pool
+    pub mint: Pubkey,                             // token accepted by the pool ($TRAVELAI)
+    pub native_sol: bool,                         // mint is wSOL, users pay and receive lamports
+    pub signal_id: [u8; 32],                      // off-chain signal identifier
+    pub status: PoolStatus,
+    pub kind: PoolKind,
+    pub resolution_source: ResolutionSource,
+    pub winning_outcome: Option<u8>,              // bucket index, set once verified
+    pub resolved_value: Option<i64>,              // verified value (range/scalar pools)
+    pub open_ts: i64,
+    pub close_ts: i64,
+    pub lock_period_secs: u32,                    // contributions stop this long before close_ts
+    pub dispute_deadline: i64,                    // end of the dispute window once proposed, of arbitration once disputed
+    pub disputer: Pubkey,                         // account that posted the dispute bond
+    pub dispute_bond: u64,                        // bond held in the vault while disputed
+    pub total_contributed: u64,                   // token base units held in the vault
+    pub unsettled_stake: u64,                     // stake still owed a payout or refund
+    pub contributors: u64,                        // Contribution accounts holding stake
+    pub open_contributions: u64,                  // Contribution accounts not yet closed
+    pub num_outcomes: u8,                         // buckets in use (2..=MAX_OUTCOMES)
+    pub paused: bool,                             // freezes contributions and settlement
+    pub limits: ContributionLimits,
+    pub community: bool,                          // created permissionlessly by `authority`
+    pub creation_bond: u64,                       // creator's bond held in the vault
+    pub creator_fee_bps: u16,                     // creator's cut of each claim
+    pub creator_fees: u64,                        // creator fees accrued in the vault
+    pub invalid: bool,                            // cancelled as invalid, bond is slashed
+    pub timeline_prediction_id: Option<u64>,      // timeline PredictionRecord mirrored on close
+    pub buckets: [OutcomeBucket; MAX_OUTCOMES],
+    pub bump: u8,
+}
+
+impl Pool {
+    pub const SIZE: usize = 32 // registry
+        + 32                   // authority
+        + 32                   // mint
+        + 1                    // native_sol
+        + 32                   // signal_id
+        + 1                    // status (enum)
+        + PoolKind::SIZE       // kind
+        + ResolutionSource::SIZE // resolution_source
+        + 1 + 1                // winning_outcome (Option<u8>)
+        + 1 + 8                // resolved_value (Option<i64>)
+        + 8                    // open_ts
+        + 8                    // close_ts
+        + 4                    // lock_period_secs
+        + 8                    // dispute_deadline
+        + 32                   // disputer
+        + 8                    // dispute_bond
+        + 8                    // total_contributed
+        + 8                    // unsettled_stake
+        + 8                    // contributors
+        + 8                    // open_contributions
+        + 1                    // num_outcomes
+        + 1                    // paused
+        + ContributionLimits::SIZE // limits
+        + 1                    // community
+        + 8                    // creation_bond
+        + 2                    // creator_fee_bps
+        + 8                    // creator_fees
+        + 1                    // invalid
+        + 1 + 8                // timeline_prediction_id (Option<u64>)
+        + OutcomeBucket::SIZE * MAX_OUTCOMES // buckets
+        + 1;                   // bump
+
+    /// Seeds of this pool's PDA, bump included, for signing vault transfers.
+    pub fn signer_seeds(&self) -> Vec<&[u8]> {
+        let mut seeds: Vec<&[u8]> = if self.community {
+            vec![COMMUNITY_POOL_SEED, self.authority.as_ref()]
+        } else {
+            vec![POOL_SEED]
+        };
+        seeds.push(self.signal_id.as_ref());
+        seeds.push(std::slice::from_ref(&self.bump));
+        seeds
+    }
+
+    /// Whether `key` is the PDA this pool's seeds derive.
+    pub fn is_pda(&self, key: &Pubkey) -> bool {
+        Pubkey::create_program_address(&self.signer_seeds(), &crate::ID)
+            .is_ok_and(|address| address == *key)
+    }
+
+    /// Stake backing the given outcome, if the index is in range.
+    pub fn outcome_total(&self, outcome: u8) -> Option<u64> {
+        (outcome < self.num_outcomes).then(|| self.buckets[outcome as usize].total)
+    }
+
+    /// Start of the lock period; contributions stop from here on.
+    pub fn lock_ts(&self) -> i64 {
+        self.close_ts.saturating_sub(self.lock_period_secs as i64)
+    }
+
+    /// Whether the pool is still before resolution or cancellation.
+    pub fn is_unresolved(&self) -> bool {
+        matches!(
+            self.status,
+            PoolStatus::Scheduled | PoolStatus::Open | PoolStatus::Locked
+        )
+    }
+
+    /// Move a pool that is not yet resolved to the phase given by the clock.
+    /// Returns the (possibly updated) status.
+    pub fn sync_status(&mut self, now: i64) -> PoolStatus {
+        if self.is_unresolved() {
+            self.status = if now < self.open_ts {
+                PoolStatus::Scheduled
+            } else if now < self.lock_ts() {
+                PoolStatus::Open
+            } else {
+                PoolStatus::Locked
+            };
+        }
+        self.status
+    }
+
+    /// Mark the recorded result final. Positions on outcomes that pay out
+    /// become unsettled. If no stake backs a paying outcome there is nobody
+    /// to pay, so the pool is cancelled and every position refunded instead.
+    pub fn mark_resolved(&mut self) -> Result<()> {
+        let mut unsettled: u64 = 0;
+        for outcome in 0..self.num_outcomes {
+            if self.outcome_pays(outcome)? {
+                unsettled = safe_add(unsettled, self.buckets[outcome as usize].total)?;
+            }
+        }
+        if unsettled == 0 {
+            return self.mark_cancelled();
+        }
+        self.unsettled_stake = unsettled;
+        self.status = PoolStatus::Resolved;
+        Ok(())
+    }
+
+    /// Void the pool; every remaining position becomes refundable.
+    pub fn mark_cancelled(&mut self) -> Result<()> {
+        self.unsettled_stake = self.staked_total()?;
+        self.status = if self.unsettled_stake == 0 {
+            PoolStatus::Settled
+        } else {
+            PoolStatus::Cancelled
+        };
+        Ok(())
+    }
+
+    /// Record a position of `stake` as paid out or refunded.
+    /// Returns true once the last position has been settled.
+    pub fn settle_position(&mut self, stake: u64) -> Result<bool> {
+        self.unsettled_stake = safe_sub(self.unsettled_stake, stake)?;
+        if self.unsettled_stake == 0 {
+            self.status = PoolStatus::Settled;
+        }
+        Ok(self.status == PoolStatus::Settled)
+    }
+
+    /// Count a Contribution account created for this pool.
+    pub fn contribution_opened(&mut self) -> Result<()> {
+        self.open_contributions = safe_add(self.open_contributions, 1)?;
+        Ok(())
+    }
+
+    /// Forget a Contribution account being closed with `amount` still staked.
+    /// Emptied positions were already uncounted from `contributors` when
+    /// withdrawn, but keep the pool open until their account is closed.
+    pub fn contribution_closed(&mut self, amount: u64) -> Result<()> {
+        if amount > 0 {
+            self.contributors = safe_sub(self.contributors, 1)?;
+        }
+        self.open_contributions = safe_sub(self.open_contributions, 1)?;
+        Ok(())
+    }
+
+    /// Whether Contribution accounts or the creation bond still depend on the
+    /// pool, so its accounts cannot be closed yet.
+    pub fn has_outstanding_accounts(&self) -> bool {
+        self.open_contributions > 0 || self.creation_bond > 0
+    }
+
+    /// Stake still backing an outcome. Lower than `total_contributed` once
+    /// early-exit penalties have been left in the pool.
+    pub fn staked_total(&self) -> Result<u64> {
+        self.buckets[..self.num_outcomes as usize]
+            .iter()
+            .try_fold(0u64, |sum, bucket| safe_add(sum, bucket.total))
+    }
+
+    /// Bucket a verified value falls into for range pools.
+    pub fn range_bucket(&self, value: i64) -> Option<u8> {
+        let PoolKind::Range { lower, upper } = self.kind else {
+            return None;
+        };
+        if value < lower {
+            return Some(0);
+        }
+        if value >= upper {
+            return Some(self.num_outcomes - 1);
+        }
+        let offset = (value as i128 - lower as i128) * self.num_outcomes as i128;
+        u8::try_from(offset / (upper as i128 - lower as i128)).ok()
+    }
+
+    /// Record a resolution on the pool, checking it fits the pool kind.
+    /// Range pools derive the winning bucket from the value.
+    pub fn apply_resolution(&mut self, resolution: Resolution) -> Result<()> {
+        match (self.kind, resolution) {
+            (PoolKind::Categorical, Resolution::Outcome(index)) => {
+                require!(index < self.num_outcomes, TimeTravelerError::InvalidOutcome);
+                self.winning_outcome = Some(index);
+            }
+            (PoolKind::Range { .. }, Resolution::Value(value)) => {
+                self.winning_outcome = self.range_bucket(value);
+                self.resolved_value = Some(value);
+            }
+            (PoolKind::Scalar { .. }, Resolution::Value(value)) => {
+                self.resolved_value = Some(value);
+            }
+            _ => return err!(TimeTravelerError::ResolutionKindMismatch),
+        }
+        Ok(())
+    }
+
+    /// Whether the recorded resolution settles the pool the same way as an
+    /// earlier `(winning_outcome, resolved_value)`. Categorical and range pools
+    /// compare the winning bucket, so a corrected value inside the same range
+    /// bucket still agrees. Scalar pools pay out by the value itself, so only
+    /// the exact value agrees.
+    pub fn same_result(&self, winning_outcome: Option<u8>, resolved_value: Option<i64>) -> bool {
+        match self.kind {
+            PoolKind::Categorical | PoolKind::Range { .. } => self.winning_outcome == winning_outcome,
+            PoolKind::Scalar { .. } => self.resolved_value == resolved_value,
+        }
+    }
+
+    /// Whether positions on `outcome` are owed anything once resolved.
+    pub fn outcome_pays(&self, outcome: u8) -> Result<bool> {
+        let total = self
+            .outcome_total(outcome)
+            .ok_or(TimeTravelerError::InvalidOutcome)?;
+        Ok(total > 0 && self.payout_for(outcome, total)? > 0)
+    }
+
+    /// Gross payout (before fees) owed to `amount` staked on `outcome`
+    /// once the pool is resolved. Returns 0 for losing positions.
+    pub fn payout_for(&self, outcome: u8, amount: u64) -> Result<u64> {
+        let outcome_total = self
+            .outcome_total(outcome)
+            .ok_or(TimeTravelerError::InvalidOutcome)?;
+        let pot = match self.kind {
+            PoolKind::Categorical | PoolKind::Range { .. } => {
+                let winning_outcome = self
+                    .winning_outcome
+                    .ok_or(TimeTravelerError::PoolNotClosed)?;
+                if winning_outcome != outcome {
+                    return Ok(0);
+                }
+                self.total_contributed
+            }
+            PoolKind::Scalar { lower, upper } => {
+                let value = self
+                    .resolved_value
+                    .ok_or(TimeTravelerError::PoolNotClosed)?
+                    .clamp(lower, upper);
+                let span = (upper as i128 - lower as i128) as u128;
+                let long_weight = (value as i128 - lower as i128) as u128;
+                let weight = if outcome == 1 { long_weight } else { span - long_weight };
+                // A side nobody backed forfeits its share to the other side
+                if self.outcome_total(1 - outcome) == Some(0) {
+                    self.total_contributed
+                } else {
+                    mul_div(self.total_contributed as u128, weight, span, Rounding::Down)
+                        .and_then(|pot| u64::try_from(pot).ok())
+                        .ok_or(TimeTravelerError::NumericalOverflow)?
+                }
+            }
+        };
+        pro_rata(amount, pot, outcome_total)
+    }
+}
+
+/// Human-readable description of a pool for wallets and explorers.
+/// Created with the pool; editable by the pool authority until it locks.
+/// PDA seeds: ["metadata", pool]
+#[account]
+pub struct PoolMetadata {
+    pub pool: Pubkey,
+    pub question: String,            // max 200 (4 + 200)
+    pub asset_symbol: String,        // max 16 (4 + 16), e.g. "SOL"
+    pub asset_mint: Pubkey,          // mint of the predicted asset (default = none)
+    pub volatility_tier: String,     // max 10 (4 + 10), "green","yellow","red"
+    pub ipfs_cid: String,            // max 64 (4 + 64), full signal document
+    pub resolution_criteria: String, // max 256 (4 + 256)
+    pub bump: u8,
+}
+
+// Strings are serialized as: 4-byte length prefix + content bytes.
+impl PoolMetadata {
+    pub const QUESTION_MAX: usize = 200;
+    pub const SYMBOL_MAX: usize = 16;
+    pub const VOL_TIER_MAX: usize = 10;
+    pub const IPFS_MAX: usize = 64;
+    pub const CRITERIA_MAX: usize = 256;
+
+    pub const SIZE: usize = 32 // pool
+        + 4 + Self::QUESTION_MAX // question
+        + 4 + Self::SYMBOL_MAX   // asset_symbol
+        + 32                     // asset_mint
+        + 4 + Self::VOL_TIER_MAX // volatility_tier
+        + 4 + Self::IPFS_MAX     // ipfs_cid
+        + 4 + Self::CRITERIA_MAX // resolution_criteria
+        + 1;                     // bump
+
+    /// Validate string lengths and overwrite every descriptive field.
+    pub fn apply(&mut self, params: PoolMetadataParams) -> Result<()> {
+        require!(
+            params.question.len() <= Self::QUESTION_MAX
+                && params.asset_symbol.len() <= Self::SYMBOL_MAX
+                && params.volatility_tier.len() <= Self::VOL_TIER_MAX
+                && params.ipfs_cid.len() <= Self::IPFS_MAX
+                && params.resolution_criteria.len() <= Self::CRITERIA_MAX,
+            TimeTravelerError::MetadataTooLong
+        );
+        self.question = params.question;
+        self.asset_symbol = params.asset_symbol;
+        self.asset_mint = params.asset_mint;
+        self.volatility_tier = params.volatility_tier;
+        self.ipfs_cid = params.ipfs_cid;
+        self.resolution_criteria = params.resolution_criteria;
+        Ok(())
+    }
+}
+
+/// Descriptive pool fields supplied at creation and on update
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PoolMetadataParams {
+    /// Question the pool answers
+    pub question: String,
+    /// Ticker of the predicted asset
+    pub asset_symbol: String,
+    /// Mint of the predicted asset (default = none, e.g. off-chain assets)
+    pub asset_mint: Pubkey,
+    /// Signal volatility tier ("green", "yellow", "red")
+    pub volatility_tier: String,
+    /// IPFS CID of the full signal document
+    pub ipfs_cid: String,
+    /// How the outcome will be decided
+    pub resolution_criteria: String,
+}
+
+/// A single contributor's position in a pool.
+/// PDA seeds: ["contrib", pool, user]
+#[account]
+pub struct Contribution {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64, // cumulative token base units contributed
+    pub outcome: u8, // bucket backed, fixed on first contribution
+    pub claimed: bool, // winnings or refund paid out
+    pub referral: Pubkey, // Referral PDA credited when winnings are claimed (default = none)
+}
+
+impl Contribution {
+    pub const SIZE: usize = 32 // pool
+        + 32                   // user
+        + 8                    // amount
+        + 1                    // outcome
+        + 1                    // claimed
+        + 32;                  // referral
+}
+
+/// Rewards owed to a referrer for contributions in one mint.
+/// PDA seeds: ["referral", registry, mint, referrer]
+#[account]
+pub struct ReferralAccount {
+    pub registry: Pubkey,
+    pub referrer: Pubkey,
+    pub mint: Pubkey,                 // mint rewards accrue and are paid in
+    pub referred_volume: u64,         // total contributions naming this referrer
+    pub referred_contributions: u64,  // number of such contributions
+    pub rewards_accrued: u64,         // lifetime rewards credited from collected fees
+    pub rewards_claimed: u64,         // lifetime rewards paid out
+    pub bump: u8,
+}
+
+impl ReferralAccount {
+    pub const SIZE: usize = 32 // registry
+        + 32                   // referrer
+        + 32                   // mint
+        + 8                    // referred_volume
+        + 8                    // referred_contributions
+        + 8                    // rewards_accrued
+        + 8                    // rewards_claimed
+        + 1;                   // bump
+}
+
+/// Running fee totals for one treasury, created alongside it by `init_treasury`.
+/// Amounts are in the treasury mint's base units, as actually received.
+/// PDA seeds: ["treasury_stats", registry, mint]
+#[account]
+pub struct TreasuryStats {
+    pub registry: Pubkey,
+    pub mint: Pubkey,
+    pub fees_collected: u64,        // protocol fees, slashed bonds and dust received
+    pub fees_withdrawn: u64,        // fees withdrawn by a FeeManager
+    pub referral_rewards_accrued: u64, // referral rewards credited from collected fees
+    pub referral_rewards_paid: u64, // referral rewards paid out to referrers
+    pub bump: u8,
+}
+
+impl TreasuryStats {
+    pub const SIZE: usize = 32 // registry
+        + 32                   // mint
+        + 8                    // fees_collected
+        + 8                    // fees_withdrawn
+        + 8                    // referral_rewards_accrued
+        + 8                    // referral_rewards_paid
+        + 1;                   // bump
+
+    /// Referral rewards credited but not yet paid; kept in the treasury.
+    pub fn referral_rewards_owed(&self) -> Result<u64> {
+        safe_sub(self.referral_rewards_accrued, self.referral_rewards_paid)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::utils::split_fee;
+
+    /// Build a pool of `kind` with one bucket per entry of `totals`.
+    pub(crate) fn pool(kind: PoolKind, totals: &[u64]) -> Pool {
+        let mut buckets = [OutcomeBucket::default(); MAX_OUTCOMES];
+        for (bucket, total) in buckets.iter_mut().zip(totals) {
+            bucket.total = *total;
+        }
+        Pool {
+            registry: Pubkey::default(),
+            authority: Pubkey::default(),
+            mint: Pubkey::default(),
+            native_sol: false,
+            signal_id: [0; 32],
+            status: PoolStatus::Locked,
+            kind,
+            resolution_source: ResolutionSource::Authority,
+            winning_outcome: None,
+            resolved_value: None,
+            open_ts: 0,
+            close_ts: 1_000,
+            lock_period_secs: 0,
+            dispute_deadline: 0,
+            disputer: Pubkey::default(),
+            dispute_bond: 0,
+            total_contributed: totals.iter().sum(),
+            unsettled_stake: 0,
+            contributors: 0,
+            open_contributions: 0,
+            num_outcomes: totals.len() as u8,
+            paused: false,
+            limits: ContributionLimits::default(),
+            community: false,
+            creation_bond: 0,
+            creator_fee_bps: 0,
+            creator_fees: 0,
+            invalid: false,
+            timeline_prediction_id: None,
+            buckets,
+            bump: 0,
+        }
+    }
+
+    fn resolved(mut pool: Pool, resolution: Resolution) -> Pool {
+        pool.apply_resolution(resolution).unwrap();
+        pool
+    }
+
+    #[test]
+    fn winners_share_the_pot_pro_rata_minus_the_fee() {
+        let pool = resolved(pool(PoolKind::Categorical, &[400, 600]), Resolution::Outcome(1));
+        // 600 on the winning side shares the whole 1000
+        assert_eq!(pool.payout_for(1, 600).unwrap(), 1_000);
+        assert_eq!(pool.payout_for(1, 150).unwrap(), 250);
+        assert_eq!(pool.payout_for(1, 1).unwrap(), 1);
+        let (payout, fee) = split_fee(pool.payout_for(1, 150).unwrap(), 250).unwrap();
+        assert_eq!((payout, fee), (243, 7));
+    }
+
+    #[test]
+    fn yes_and_no_sides_are_tracked_separately() {
+        // Outcome 0 = YES, 1 = NO
+        let pool = resolved(pool(PoolKind::Categorical, &[250, 750]), Resolution::Outcome(0));
+        assert_eq!(pool.outcome_total(0), Some(250));
+        assert_eq!(pool.outcome_total(1), Some(750));
+        assert_eq!(pool.outcome_total(2), None);
+        assert_eq!(pool.payout_for(0, 250).unwrap(), 1_000);
+        assert_eq!(pool.payout_for(1, 750).unwrap(), 0);
+    }
+
+    #[test]
+    fn categorical_winner_takes_whole_pot() {
+        let pool = resolved(pool(PoolKind::Categorical, &[100, 300, 600]), Resolution::Outcome(0));
+        assert_eq!(pool.payout_for(0, 100).unwrap(), 1_000);
+        assert_eq!(pool.payout_for(0, 25).unwrap(), 250);
+        assert_eq!(pool.payout_for(1, 300).unwrap(), 0);
+        assert!(pool.outcome_pays(0).unwrap());
+        assert!(!pool.outcome_pays(1).unwrap());
+        assert!(pool.outcome_pays(3).is_err());
+    }
+
+
+    #[test]
+    fn categorical_pool_accepts_every_declared_outcome() {
+        let totals = [10; MAX_OUTCOMES];
+        let pool = resolved(pool(PoolKind::Categorical, &totals), Resolution::Outcome(MAX_OUTCOMES as u8 - 1));
+        assert_eq!(pool.payout_for(MAX_OUTCOMES as u8 - 1, 10).unwrap(), 10 * MAX_OUTCOMES as u64);
+        let mut pool = pool;
+        assert!(pool.apply_resolution(Resolution::Outcome(MAX_OUTCOMES as u8)).is_err());
+    }
+
+    #[test]
+    fn range_bucket_edges() {
+        let pool = pool(PoolKind::Range { lower: 0, upper: 100 }, &[1, 1, 1, 1]);
+        assert_eq!(pool.range_bucket(i64::MIN), Some(0));
+        assert_eq!(pool.range_bucket(-1), Some(0));
+        assert_eq!(pool.range_bucket(0), Some(0));
+        assert_eq!(pool.range_bucket(24), Some(0));
+        assert_eq!(pool.range_bucket(25), Some(1));
+        assert_eq!(pool.range_bucket(75), Some(3));
+        assert_eq!(pool.range_bucket(99), Some(3));
+        assert_eq!(pool.range_bucket(100), Some(3));
+        assert_eq!(pool.range_bucket(i64::MAX), Some(3));
+    }
+
+    #[test]
+    fn range_resolution_records_bucket_and_value() {
+        let pool = resolved(
+            pool(PoolKind::Range { lower: -20, upper: 40 }, &[10, 20, 30]),
+            Resolution::Value(5),
+        );
+        assert_eq!(pool.winning_outcome, Some(1));
+        assert_eq!(pool.resolved_value, Some(5));
+        assert_eq!(pool.payout_for(1, 20).unwrap(), 60);
+        assert_eq!(pool.payout_for(0, 10).unwrap(), 0);
+        assert!(!pool.outcome_pays(2).unwrap());
+    }
+
+    #[test]
+    fn resolution_must_match_pool_kind() {
+        let mut categorical = pool(PoolKind::Categorical, &[1, 1]);
+        assert!(categorical.apply_resolution(Resolution::Value(1)).is_err());
+        assert!(categorical.apply_resolution(Resolution::Outcome(2)).is_err());
+        let mut scalar = pool(PoolKind::Scalar { lower: 0, upper: 10 }, &[1, 1]);
+        assert!(scalar.apply_resolution(Resolution::Outcome(0)).is_err());
+    }
+
+    #[test]
+    fn scalar_payout_at_and_beyond_bounds() {
+        let kind = PoolKind::Scalar { lower: -20, upper: 40 };
+        // short = 100, long = 300
+        for (value, short, long) in [
+            (-20, 400, 0),
+            (-1_000, 400, 0),
+            (40, 0, 400),
+            (1_000, 0, 400),
+            (10, 200, 200),
+        ] {
+            let pool = resolved(pool(kind, &[100, 300]), Resolution::Value(value));
+            assert_eq!(pool.payout_for(0, 100).unwrap(), short, "short at {value}");
+            assert_eq!(pool.payout_for(1, 300).unwrap(), long, "long at {value}");
+            assert_eq!(pool.outcome_pays(0).unwrap(), short > 0);
+            assert_eq!(pool.outcome_pays(1).unwrap(), long > 0);
+        }
+    }
+
+    #[test]
+    fn scalar_side_with_no_stake_forfeits_to_other_side() {
+        let kind = PoolKind::Scalar { lower: 0, upper: 100 };
+        let pool = resolved(pool(kind, &[0, 300]), Resolution::Value(0));
+        assert_eq!(pool.payout_for(1, 300).unwrap(), 300);
+        assert_eq!(pool.payout_for(1, 100).unwrap(), 100);
+        assert!(!pool.outcome_pays(0).unwrap());
+        assert!(pool.outcome_pays(1).unwrap());
+    }
+
+    #[test]
+    fn cancelled_pool_refunds_every_position() {
+        let mut pool = pool(PoolKind::Categorical, &[100, 300]);
+        pool.mark_cancelled().unwrap();
+        assert_eq!(pool.status, PoolStatus::Cancelled);
+        assert_eq!(pool.unsettled_stake, 400);
+        // Refunds are the stake itself when no penalties were left behind
+        assert_eq!(pro_rata(100, pool.total_contributed, pool.staked_total().unwrap()).unwrap(), 100);
+        assert!(!pool.settle_position(100).unwrap());
+        assert!(pool.settle_position(300).unwrap());
+        assert_eq!(pool.status, PoolStatus::Settled);
+    }
+
+    #[test]
+    fn arbitration_compares_what_the_pool_pays_on() {
+        let range = resolved(
+            pool(PoolKind::Range { lower: 0, upper: 100 }, &[1, 1, 1, 1]),
+            Resolution::Value(30),
+        );
+        assert!(range.same_result(Some(1), Some(49)));
+        assert!(!range.same_result(Some(2), Some(50)));
+
+        let scalar = resolved(
+            pool(PoolKind::Scalar { lower: 0, upper: 100 }, &[1, 1]),
+            Resolution::Value(30),
+        );
+        assert!(scalar.same_result(None, Some(30)));
+        assert!(!scalar.same_result(None, Some(31)));
+
+        let categorical = resolved(pool(PoolKind::Categorical, &[1, 1]), Resolution::Outcome(0));
+        assert!(categorical.same_result(Some(0), None));
+        assert!(!categorical.same_result(Some(1), None));
+    }
+
+    #[test]
+    fn role_table_fills_and_frees_slots() {
+        let mut roles = Roles {
+            registry: Pubkey::default(),
+            members: [RoleMember::default(); MAX_ROLE_MEMBERS],
+            bump: 0,
+        };
+        let keys: Vec<Pubkey> = (0..MAX_ROLE_MEMBERS).map(|_| Pubkey::new_unique()).collect();
+        for key in &keys {
+            roles.grant(*key, Role::Resolver).unwrap();
+        }
+
+        // Full table: new keys are refused, existing members can gain roles
+        let outsider = Pubkey::new_unique();
+        assert!(roles.grant(outsider, Role::Pauser).is_err());
+        roles.grant(keys[0], Role::Pauser).unwrap();
+        assert!(roles.has_role(&keys[0], Role::Resolver));
+        assert!(roles.has_role(&keys[0], Role::Pauser));
+        assert!(!roles.has_role(&keys[1], Role::Pauser));
+
+        // Dropping one of two roles keeps the slot, dropping the last frees it
+        roles.revoke(&keys[0], Role::Resolver).unwrap();
+        assert!(roles.grant(outsider, Role::Pauser).is_err());
+        roles.revoke(&keys[1], Role::Resolver).unwrap();
+        roles.grant(outsider, Role::Pauser).unwrap();
+        assert!(roles.has_role(&outsider, Role::Pauser));
+        assert!(!roles.has_role(&keys[1], Role::Resolver));
+
+        assert!(roles.revoke(&keys[1], Role::Resolver).is_err());
+        assert!(roles.revoke(&keys[2], Role::ConfigAdmin).is_err());
+    }
+
+
+    #[test]
+    fn registry_authority_holds_every_role() {
+        let authority = Pubkey::new_unique();
+        let member = Pubkey::new_unique();
+        let mut roles = Roles {
+            registry: Pubkey::default(),
+            members: [RoleMember::default(); MAX_ROLE_MEMBERS],
+            bump: 0,
+        };
+        roles.grant(member, Role::Resolver).unwrap();
+        let registry = Registry {
+            authority,
+            pending_authority: Pubkey::default(),
+            version: 1,
+            fee_bps: 0,
+            oracle: Pubkey::default(),
+            oracle_type: OracleType::Pyth,
+            secondary_oracle: Pubkey::default(),
+            secondary_oracle_type: OracleType::Pyth,
+            max_price_age_secs: 0,
+            max_conf_bps: 0,
+            max_deviation_bps: 0,
+            referral_bps: 0,
+            dispute_window_secs: 0,
+            dispute_bond: 0,
+            arbiter: Pubkey::default(),
+            arbitration_window_secs: 0,
+            early_exit_penalty_bps: 0,
+            exit_lockout_secs: 0,
+            default_limits: ContributionLimits::default(),
+            default_lock_period_secs: 0,
+            community_bond: 0,
+            creator_fee_bps: 0,
+            timeline_program: Pubkey::default(),
+            close_grace_secs: 0,
+            paused: false,
+            config_version: 0,
+            bump: 0,
+        };
+        assert!(registry.has_role(&authority, None, Role::ConfigAdmin));
+        assert!(registry.has_role(&member, Some(&roles), Role::Resolver));
+        assert!(!registry.has_role(&member, Some(&roles), Role::ConfigAdmin));
+        assert!(!registry.has_role(&member, None, Role::Resolver));
+    }
+
+    #[test]
+    fn exit_penalties_feed_the_pot() {
+        // 10 units of early-exit penalty left behind: buckets hold 100, the vault 110
+        let mut pool = pool(PoolKind::Categorical, &[60, 40]);
+        pool.total_contributed = 110;
+        assert_eq!(pool.staked_total().unwrap(), 100);
+        let pool = resolved(pool, Resolution::Outcome(0));
+        assert_eq!(pool.payout_for(0, 60).unwrap(), 110);
+        assert_eq!(pool.payout_for(0, 30).unwrap(), 55);
+    }
+
+    #[test]
+    fn mark_resolved_tracks_paying_stake() {
+        let mut pool = resolved(pool(PoolKind::Categorical, &[100, 300]), Resolution::Outcome(1));
+        pool.mark_resolved().unwrap();
+        assert_eq!(pool.status, PoolStatus::Resolved);
+        assert_eq!(pool.unsettled_stake, 300);
+        assert!(!pool.settle_position(200).unwrap());
+        assert!(pool.settle_position(100).unwrap());
+        assert_eq!(pool.status, PoolStatus::Settled);
+    }
+
+    #[test]
+    fn resolution_without_winners_refunds_everyone() {
+        let mut pool = resolved(pool(PoolKind::Categorical, &[100, 0, 300]), Resolution::Outcome(1));
+        pool.mark_resolved().unwrap();
+        assert_eq!(pool.status, PoolStatus::Cancelled);
+        assert_eq!(pool.unsettled_stake, 400);
+    }
+
+    #[test]
+    fn community_pools_derive_from_their_own_namespace() {
+        let mut official = pool(PoolKind::Categorical, &[0, 0]);
+        let (address, bump) = Pubkey::find_program_address(&[POOL_SEED, &official.signal_id], &crate::ID);
+        official.bump = bump;
+        assert!(official.is_pda(&address));
+
+        let mut community = official.clone();
+        community.community = true;
+        assert!(!community.is_pda(&address));
+        let (address, bump) = Pubkey::find_program_address(
+            &[COMMUNITY_POOL_SEED, community.authority.as_ref(), &community.signal_id],
+            &crate::ID,
+        );
+        community.bump = bump;
+        assert!(community.is_pda(&address));
+    }
+
+    #[test]
+    fn withdrawn_position_blocks_close_until_its_account_is_closed() {
+        let mut pool = pool(PoolKind::Categorical, &[0, 0]);
+        pool.contribution_opened().unwrap();
+        // A full withdrawal uncounts the contributor but the account stays open
+        assert_eq!(pool.contributors, 0);
+        assert!(pool.has_outstanding_accounts());
+
+        pool.contribution_closed(0).unwrap();
+        assert_eq!(pool.contributors, 0);
+        assert!(!pool.has_outstanding_accounts());
+        assert!(pool.contribution_closed(0).is_err());
+
+        pool.creation_bond = 1;
+        assert!(pool.has_outstanding_accounts());
+    }
+}
 // State accounts
@@ -0,0 +1,186 @@
+// Math helpers
+use anchor_lang::prelude::*;
+
+use crate::errors::TimeTravelerError;
+
+/// Basis point denominator (10000 = 100%)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Direction to round a division that does not come out even.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Rounding {
+    /// Toward zero
+    Down,
+    /// Away from zero
+    Up,
+}
+
+/// `a * b / denominator` with the given rounding.
+/// Returns `None` on division by zero or if `a * b` overflows u128.
+pub fn mul_div(a: u128, b: u128, denominator: u128, rounding: Rounding) -> Option<u128> {
+    if denominator == 0 {
+        return None;
+    }
+    let product = a.checked_mul(b)?;
+    let quotient = product / denominator;
+    match rounding {
+        Rounding::Up if product % denominator != 0 => quotient.checked_add(1),
+        _ => Some(quotient),
+    }
+}
+
+/// `a * b / denominator` for token amounts, computed in u128.
+/// Fails if the denominator is zero or the result does not fit in u64.
+pub fn mul_div_u64(a: u64, b: u64, denominator: u64, rounding: Rounding) -> Result<u64> {
+    mul_div(a as u128, b as u128, denominator as u128, rounding)
+        .and_then(|value| u64::try_from(value).ok())
+        .ok_or_else(|| error!(TimeTravelerError::NumericalOverflow))
+}
+
+/// Share of `numerator / denominator` owed to `amount`, rounded down so that
+/// the sum of all shares never exceeds the pot.
+pub fn pro_rata(amount: u64, numerator: u64, denominator: u64) -> Result<u64> {
+    mul_div_u64(amount, numerator, denominator, Rounding::Down)
+}
+
+/// Portion of `amount` represented by `bps`.
+pub fn apply_bps(amount: u64, bps: u16, rounding: Rounding) -> Result<u64> {
+    mul_div_u64(amount, bps as u64, BPS_DENOMINATOR, rounding)
+}
+
+/// Split `amount` into `(net, fee)` where the fee is `fee_bps` of the amount
+/// rounded up in favour of the protocol. `net + fee == amount` always holds.
+pub fn split_fee(amount: u64, fee_bps: u16) -> Result<(u64, u64)> {
+    require!(
+        fee_bps as u64 <= BPS_DENOMINATOR,
+        TimeTravelerError::InvalidConfig
+    );
+    let fee = apply_bps(amount, fee_bps, Rounding::Up)?;
+    Ok((safe_sub(amount, fee)?, fee))
+}
+
+/// `a + b`, failing with `NumericalOverflow`.
+pub fn safe_add(a: u64, b: u64) -> Result<u64> {
+    a.checked_add(b)
+        .ok_or_else(|| error!(TimeTravelerError::NumericalOverflow))
+}
+
+/// `a - b`, failing with `NumericalOverflow` on underflow.
+pub fn safe_sub(a: u64, b: u64) -> Result<u64> {
+    a.checked_sub(b)
+        .ok_or_else(|| error!(TimeTravelerError::NumericalOverflow))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn mul_div_rounds_in_requested_direction() {
+        assert_eq!(mul_div(10, 1, 3, Rounding::Down), Some(3));
+        assert_eq!(mul_div(10, 1, 3, Rounding::Up), Some(4));
+        assert_eq!(mul_div(9, 1, 3, Rounding::Up), Some(3));
+        assert_eq!(mul_div(0, 5, 3, Rounding::Up), Some(0));
+    }
+
+    #[test]
+    fn mul_div_rejects_zero_denominator_and_overflow() {
+        assert_eq!(mul_div(1, 1, 0, Rounding::Down), None);
+        assert_eq!(mul_div(u128::MAX, 2, 1, Rounding::Down), None);
+        assert_eq!(mul_div(u128::MAX, 1, 1, Rounding::Up), Some(u128::MAX));
+    }
+
+    #[test]
+    fn mul_div_u64_handles_full_range_intermediates() {
+        assert_eq!(mul_div_u64(u64::MAX, u64::MAX, u64::MAX, Rounding::Down).unwrap(), u64::MAX);
+        assert!(mul_div_u64(u64::MAX, 2, 1, Rounding::Down).is_err());
+        assert!(mul_div_u64(1, 1, 0, Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn pro_rata_rounds_down() {
+        assert_eq!(pro_rata(1, 100, 3).unwrap(), 33);
+        assert_eq!(pro_rata(50, 300, 100).unwrap(), 150);
+        assert!(pro_rata(1, 1, 0).is_err());
+    }
+
+    #[test]
+    fn apply_bps_edges() {
+        assert_eq!(apply_bps(12_345, 0, Rounding::Up).unwrap(), 0);
+        assert_eq!(apply_bps(12_345, 10_000, Rounding::Down).unwrap(), 12_345);
+        assert_eq!(apply_bps(12_345, 250, Rounding::Down).unwrap(), 308);
+        assert_eq!(apply_bps(12_345, 250, Rounding::Up).unwrap(), 309);
+        assert_eq!(apply_bps(u64::MAX, 10_000, Rounding::Down).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn split_fee_rounds_fee_up() {
+        assert_eq!(split_fee(1_000, 250).unwrap(), (975, 25));
+        assert_eq!(split_fee(1, 1).unwrap(), (0, 1));
+        assert_eq!(split_fee(1_000, 0).unwrap(), (1_000, 0));
+        assert_eq!(split_fee(1_000, 10_000).unwrap(), (0, 1_000));
+        assert!(split_fee(1_000, 10_001).is_err());
+    }
+
+    #[test]
+    fn safe_add_sub_report_overflow() {
+        assert_eq!(safe_add(1, 2).unwrap(), 3);
+        assert!(safe_add(u64::MAX, 1).is_err());
+        assert_eq!(safe_sub(3, 2).unwrap(), 1);
+        assert!(safe_sub(2, 3).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn prop_rounding_brackets_exact_result(a in any::<u64>(), b in any::<u64>(), d in 1..=u64::MAX) {
+            let down = mul_div(a as u128, b as u128, d as u128, Rounding::Down).unwrap();
+            let up = mul_div(a as u128, b as u128, d as u128, Rounding::Up).unwrap();
+            let exact = (a as u128) * (b as u128);
+            prop_assert!(down * d as u128 <= exact);
+            prop_assert!(up * d as u128 >= exact);
+            prop_assert!(up - down <= 1);
+            let remainder = exact % d as u128;
+            prop_assert_eq!(up == down, remainder == 0);
+        }
+
+        #[test]
+        fn prop_mul_div_by_self_is_identity(a in any::<u64>(), b in 1..=u64::MAX) {
+            prop_assert_eq!(mul_div_u64(a, b, b, Rounding::Down).unwrap(), a);
+            prop_assert_eq!(mul_div_u64(a, b, b, Rounding::Up).unwrap(), a);
+        }
+
+        #[test]
+        fn prop_pro_rata_shares_never_exceed_pot(
+            stakes in prop::collection::vec(1..=u32::MAX as u64, 1..16),
+            pot in any::<u64>(),
+        ) {
+            let total: u64 = stakes.iter().sum();
+            let paid: u128 = stakes
+                .iter()
+                .map(|stake| pro_rata(*stake, pot, total).unwrap() as u128)
+                .sum();
+            prop_assert!(paid <= pot as u128);
+            // Rounding loses at most one unit per share
+            prop_assert!(pot as u128 - paid < stakes.len() as u128);
+        }
+
+        #[test]
+        fn prop_split_fee_conserves_amount(amount in any::<u64>(), bps in 0..=10_000u16) {
+            let (net, fee) = split_fee(amount, bps).unwrap();
+            prop_assert_eq!(net as u128 + fee as u128, amount as u128);
+            prop_assert!(fee >= apply_bps(amount, bps, Rounding::Down).unwrap());
+            prop_assert!(fee <= amount);
+        }
+
+        #[test]
+        fn prop_apply_bps_is_monotonic(amount in any::<u64>(), a in 0..=10_000u16, b in 0..=10_000u16) {
+            let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+            prop_assert!(
+                apply_bps(amount, lo, Rounding::Down).unwrap()
+                    <= apply_bps(amount, hi, Rounding::Down).unwrap()
+            );
+            prop_assert!(apply_bps(amount, hi, Rounding::Down).unwrap() <= amount);
+        }
+    }
+}
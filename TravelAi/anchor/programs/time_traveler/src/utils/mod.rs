@@ -1,7 +1,11 @@
 // mod.rs for utils
 pub mod math;
+pub mod native_sol;
 pub mod price_oracle;
+pub mod timeline;
 
 /// Common utility exports
 pub use math::*;
+pub use native_sol::*;
 pub use price_oracle::*;
+pub use timeline::*;
@@ -0,0 +1,123 @@
+// Wrapped SOL helpers
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token_interface::{self, CloseAccount, SyncNative, TransferChecked};
+
+/// Whether `mint` and `token_program` describe classic SPL wrapped SOL,
+/// the only combination native-SOL pools accept.
+pub fn is_wrapped_sol(mint: &Pubkey, token_program: &Pubkey) -> bool {
+    *mint == native_mint::ID && *token_program == anchor_spl::token::ID
+}
+
+/// Move `lamports` from a system account into a wSOL token account and
+/// sync its token balance.
+pub fn wrap_sol<'info>(
+    system_program: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    wsol_account: AccountInfo<'info>,
+    lamports: u64,
+) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            system_program,
+            Transfer {
+                from,
+                to: wsol_account.clone(),
+            },
+        ),
+        lamports,
+    )?;
+    token_interface::sync_native(CpiContext::new(
+        token_program,
+        SyncNative {
+            account: wsol_account,
+        },
+    ))
+}
+
+/// Pay `amount` out of a PDA-owned wSOL vault as lamports.
+/// The tokens go through `unwrap_account`, a temporary wSOL account with the
+/// same owner, which is then closed to `recipient` so that it receives the
+/// amount plus the temporary account's rent.
+#[allow(clippy::too_many_arguments)]
+pub fn unwrap_sol<'info>(
+    token_program: AccountInfo<'info>,
+    vault: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    unwrap_account: AccountInfo<'info>,
+    owner: AccountInfo<'info>,
+    recipient: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: vault,
+                mint,
+                to: unwrap_account.clone(),
+                authority: owner.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )?;
+    token_interface::close_account(CpiContext::new_with_signer(
+        token_program,
+        CloseAccount {
+            account: unwrap_account,
+            destination: recipient,
+            authority: owner,
+        },
+        signer_seeds,
+    ))
+}
+
+/// Pay `amount` out of a PDA-owned wSOL vault as lamports to `recipient`
+/// when `payer`, a different signer, funded `unwrap_account`. The temporary
+/// account is closed to `payer`, who forwards `amount` and keeps only the
+/// returned rent.
+#[allow(clippy::too_many_arguments)]
+pub fn unwrap_sol_to<'info>(
+    system_program: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    vault: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    unwrap_account: AccountInfo<'info>,
+    owner: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    recipient: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    unwrap_sol(
+        token_program,
+        vault,
+        mint,
+        unwrap_account,
+        owner,
+        payer.clone(),
+        signer_seeds,
+        amount,
+        decimals,
+    )?;
+    if payer.key == recipient.key {
+        return Ok(());
+    }
+    system_program::transfer(
+        CpiContext::new(
+            system_program,
+            Transfer {
+                from: payer,
+                to: recipient,
+            },
+        ),
+        amount,
+    )
+}
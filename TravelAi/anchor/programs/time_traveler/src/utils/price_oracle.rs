@@ -0,0 +1,441 @@
+// Pyth/Switchboard helpers
+use anchor_lang::prelude::*;
+
+use crate::errors::TimeTravelerError;
+use crate::state::OracleType;
+use crate::utils::math::BPS_DENOMINATOR;
+
+/// Magic number at the start of every Pyth account
+pub const PYTH_MAGIC: u32 = 0xa1b2_c3d4;
+/// Supported Pyth account layout version
+pub const PYTH_VERSION: u32 = 2;
+/// Pyth account type tag for price accounts
+pub const PYTH_ACCOUNT_TYPE_PRICE: u32 = 3;
+/// Aggregate status meaning the price is currently valid
+pub const PYTH_STATUS_TRADING: u32 = 1;
+
+// Byte offsets into a Pyth v2 price account
+const MAGIC_OFFSET: usize = 0;
+const VERSION_OFFSET: usize = 4;
+const ACCOUNT_TYPE_OFFSET: usize = 8;
+const EXPO_OFFSET: usize = 20;
+const TIMESTAMP_OFFSET: usize = 96;
+const AGG_PRICE_OFFSET: usize = 208;
+const AGG_CONF_OFFSET: usize = 216;
+const AGG_STATUS_OFFSET: usize = 224;
+
+/// Minimum length of a Pyth price account we can read
+pub const PYTH_PRICE_ACCOUNT_MIN_LEN: usize = 240;
+
+/// Anchor discriminator of a Switchboard on-demand `PullFeedAccountData`
+pub const SWITCHBOARD_PULL_FEED_DISCRIMINATOR: [u8; 8] = [196, 27, 108, 196, 10, 215, 219, 40];
+/// Decimal places of every Switchboard on-demand value
+pub const SWITCHBOARD_PRECISION: u32 = 18;
+
+// Byte offsets into a Switchboard on-demand pull feed account (repr(C), after discriminator)
+const SB_MIN_SAMPLE_SIZE_OFFSET: usize = 2215;
+const SB_LAST_UPDATE_TIMESTAMP_OFFSET: usize = 2216;
+const SB_RESULT_VALUE_OFFSET: usize = 2264;
+const SB_RESULT_STD_DEV_OFFSET: usize = 2280;
+const SB_RESULT_NUM_SAMPLES_OFFSET: usize = 2360;
+
+/// Length of a Switchboard on-demand pull feed account
+pub const SWITCHBOARD_PULL_FEED_LEN: usize = 3208;
+
+/// Price read from any supported oracle.
+/// The real price is `value * 10^expo`, with the same scaling for `conf`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OraclePrice {
+    pub value: i64,
+    pub expo: i32,
+    pub conf: u64,
+    pub timestamp: i64,
+}
+
+impl OraclePrice {
+    /// Value expressed with exponent `expo`, truncating toward zero when
+    /// precision is dropped. Returns `None` on overflow.
+    pub fn scaled_to(&self, expo: i32) -> Option<i64> {
+        let diff = self.expo.checked_sub(expo)?;
+        let factor = 10i64.checked_pow(diff.unsigned_abs())?;
+        if diff >= 0 {
+            self.value.checked_mul(factor)
+        } else {
+            self.value.checked_div(factor)
+        }
+    }
+}
+
+/// Reject prices published more than `max_age_secs` before or after
+/// `reference_ts`, so a resolver cannot wait for a later price that suits them.
+pub fn check_price_age(price: &OraclePrice, reference_ts: i64, max_age_secs: u32) -> Result<()> {
+    let age = reference_ts.saturating_sub(price.timestamp);
+    require!(
+        age <= max_age_secs as i64,
+        TimeTravelerError::StaleOraclePrice
+    );
+    require!(
+        price.timestamp.saturating_sub(reference_ts) <= max_age_secs as i64,
+        TimeTravelerError::OraclePriceTooLate
+    );
+    Ok(())
+}
+
+/// Reject prices whose confidence interval exceeds `max_conf_bps` of the price.
+pub fn check_confidence(price: &OraclePrice, max_conf_bps: u16) -> Result<()> {
+    let conf = price.conf as u128 * BPS_DENOMINATOR as u128;
+    let limit = price.value.unsigned_abs() as u128 * max_conf_bps as u128;
+    require!(conf <= limit, TimeTravelerError::OracleConfidenceTooWide);
+    Ok(())
+}
+
+/// Reject two prices that differ by more than `max_deviation_bps` of the first.
+pub fn check_deviation(primary: &OraclePrice, secondary: &OraclePrice, max_deviation_bps: u16) -> Result<()> {
+    let expo = primary.expo.min(secondary.expo);
+    let a = primary.scaled_to(expo).ok_or(TimeTravelerError::NumericalOverflow)?;
+    let b = secondary.scaled_to(expo).ok_or(TimeTravelerError::NumericalOverflow)?;
+    let gap = (a as i128 - b as i128).unsigned_abs() * BPS_DENOMINATOR as u128;
+    let limit = a.unsigned_abs() as u128 * max_deviation_bps as u128;
+    require!(gap <= limit, TimeTravelerError::OracleDeviationTooLarge);
+    Ok(())
+}
+
+/// Read the current price from an oracle account of the given type.
+pub fn read_oracle_price(oracle_type: OracleType, account: &AccountInfo) -> Result<OraclePrice> {
+    let data = account.try_borrow_data()?;
+    match oracle_type {
+        OracleType::Pyth => parse_pyth_price(&data),
+        OracleType::Switchboard => parse_switchboard_price(&data),
+    }
+}
+
+/// Parse raw Pyth v2 price account data.
+/// Fails if the account is not a price account or the price is not trading.
+pub fn parse_pyth_price(data: &[u8]) -> Result<OraclePrice> {
+    require!(
+        data.len() >= PYTH_PRICE_ACCOUNT_MIN_LEN,
+        TimeTravelerError::InvalidOracleAccount
+    );
+    require!(
+        read_u32(data, MAGIC_OFFSET) == PYTH_MAGIC
+            && read_u32(data, VERSION_OFFSET) == PYTH_VERSION
+            && read_u32(data, ACCOUNT_TYPE_OFFSET) == PYTH_ACCOUNT_TYPE_PRICE,
+        TimeTravelerError::InvalidOracleAccount
+    );
+    require!(
+        read_u32(data, AGG_STATUS_OFFSET) == PYTH_STATUS_TRADING,
+        TimeTravelerError::OraclePriceUnavailable
+    );
+
+    Ok(OraclePrice {
+        value: read_i64(data, AGG_PRICE_OFFSET),
+        expo: read_u32(data, EXPO_OFFSET) as i32,
+        conf: read_u64(data, AGG_CONF_OFFSET),
+        timestamp: read_i64(data, TIMESTAMP_OFFSET),
+    })
+}
+
+/// Parse raw Switchboard on-demand pull feed data from its current result.
+/// Fails if the result has fewer samples than the feed's minimum sample size.
+pub fn parse_switchboard_price(data: &[u8]) -> Result<OraclePrice> {
+    require!(
+        data.len() >= SWITCHBOARD_PULL_FEED_LEN
+            && data[..8] == SWITCHBOARD_PULL_FEED_DISCRIMINATOR,
+        TimeTravelerError::InvalidOracleAccount
+    );
+    let num_samples = data[SB_RESULT_NUM_SAMPLES_OFFSET];
+    require!(
+        num_samples > 0 && num_samples >= data[SB_MIN_SAMPLE_SIZE_OFFSET],
+        TimeTravelerError::OraclePriceUnavailable
+    );
+
+    let (value, scale) = fit_decimal(read_i128(data, SB_RESULT_VALUE_OFFSET), SWITCHBOARD_PRECISION)
+        .ok_or(TimeTravelerError::NumericalOverflow)?;
+    let conf = rescale_decimal(read_i128(data, SB_RESULT_STD_DEV_OFFSET), SWITCHBOARD_PRECISION, scale)
+        .and_then(|conf| u64::try_from(conf.unsigned_abs()).ok())
+        .ok_or(TimeTravelerError::NumericalOverflow)?;
+
+    Ok(OraclePrice {
+        value,
+        expo: -(scale as i32),
+        conf,
+        timestamp: read_i64(data, SB_LAST_UPDATE_TIMESTAMP_OFFSET),
+    })
+}
+
+/// Drop decimal places until the mantissa fits in an i64.
+fn fit_decimal(mut mantissa: i128, mut scale: u32) -> Option<(i64, u32)> {
+    loop {
+        if let Ok(value) = i64::try_from(mantissa) {
+            return Some((value, scale));
+        }
+        scale = scale.checked_sub(1)?;
+        mantissa /= 10;
+    }
+}
+
+/// Express `mantissa * 10^-scale` with `target_scale` decimal places.
+fn rescale_decimal(mantissa: i128, scale: u32, target_scale: u32) -> Option<i128> {
+    if scale >= target_scale {
+        Some(mantissa / 10i128.checked_pow(scale - target_scale)?)
+    } else {
+        mantissa.checked_mul(10i128.checked_pow(target_scale - scale)?)
+    }
+}
+
+fn read_i128(data: &[u8], offset: usize) -> i128 {
+    i128::from_le_bytes(data[offset..offset + 16].try_into().unwrap())
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+fn read_i64(data: &[u8], offset: usize) -> i64 {
+    i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a fake Pyth price account with the given aggregate values.
+    fn fake_pyth_account(price: i64, expo: i32, conf: u64, publish_time: i64, status: u32) -> Vec<u8> {
+        let mut data = vec![0u8; 3312];
+        data[MAGIC_OFFSET..MAGIC_OFFSET + 4].copy_from_slice(&PYTH_MAGIC.to_le_bytes());
+        data[VERSION_OFFSET..VERSION_OFFSET + 4].copy_from_slice(&PYTH_VERSION.to_le_bytes());
+        data[ACCOUNT_TYPE_OFFSET..ACCOUNT_TYPE_OFFSET + 4]
+            .copy_from_slice(&PYTH_ACCOUNT_TYPE_PRICE.to_le_bytes());
+        data[EXPO_OFFSET..EXPO_OFFSET + 4].copy_from_slice(&expo.to_le_bytes());
+        data[TIMESTAMP_OFFSET..TIMESTAMP_OFFSET + 8].copy_from_slice(&publish_time.to_le_bytes());
+        data[AGG_PRICE_OFFSET..AGG_PRICE_OFFSET + 8].copy_from_slice(&price.to_le_bytes());
+        data[AGG_CONF_OFFSET..AGG_CONF_OFFSET + 8].copy_from_slice(&conf.to_le_bytes());
+        data[AGG_STATUS_OFFSET..AGG_STATUS_OFFSET + 4].copy_from_slice(&status.to_le_bytes());
+        data
+    }
+
+    /// Build a fake Switchboard on-demand pull feed with the given current result.
+    fn fake_switchboard_account(
+        value: i128,
+        std_dev: i128,
+        timestamp: i64,
+        num_samples: u8,
+        min_sample_size: u8,
+    ) -> Vec<u8> {
+        let mut data = vec![0u8; SWITCHBOARD_PULL_FEED_LEN];
+        data[..8].copy_from_slice(&SWITCHBOARD_PULL_FEED_DISCRIMINATOR);
+        data[SB_MIN_SAMPLE_SIZE_OFFSET] = min_sample_size;
+        data[SB_LAST_UPDATE_TIMESTAMP_OFFSET..SB_LAST_UPDATE_TIMESTAMP_OFFSET + 8]
+            .copy_from_slice(&timestamp.to_le_bytes());
+        data[SB_RESULT_VALUE_OFFSET..SB_RESULT_VALUE_OFFSET + 16].copy_from_slice(&value.to_le_bytes());
+        data[SB_RESULT_STD_DEV_OFFSET..SB_RESULT_STD_DEV_OFFSET + 16].copy_from_slice(&std_dev.to_le_bytes());
+        data[SB_RESULT_NUM_SAMPLES_OFFSET] = num_samples;
+        data
+    }
+
+    #[test]
+    fn parses_trading_pyth_price() {
+        let data = fake_pyth_account(12_345_678, -8, 1_000, 1_700_000_000, PYTH_STATUS_TRADING);
+        let price = parse_pyth_price(&data).unwrap();
+        assert_eq!(
+            price,
+            OraclePrice { value: 12_345_678, expo: -8, conf: 1_000, timestamp: 1_700_000_000 }
+        );
+    }
+
+    #[test]
+    fn rejects_non_trading_pyth_price() {
+        let data = fake_pyth_account(1, -8, 0, 0, 0);
+        assert!(parse_pyth_price(&data).is_err());
+    }
+
+    #[test]
+    fn rejects_wrong_pyth_magic_and_short_data() {
+        let mut data = fake_pyth_account(1, -8, 0, 0, PYTH_STATUS_TRADING);
+        data[0] = 0;
+        assert!(parse_pyth_price(&data).is_err());
+        assert!(parse_pyth_price(&[0u8; 16]).is_err());
+    }
+
+    /// `whole.frac` in Switchboard's 18-decimal fixed point
+    fn sb_decimal(units: i128, decimals: u32) -> i128 {
+        units * 10i128.pow(SWITCHBOARD_PRECISION - decimals)
+    }
+
+    #[test]
+    fn parses_switchboard_pull_feed() {
+        // 123.450 +/- 0.25, narrowed to fit an i64 mantissa
+        let data = fake_switchboard_account(sb_decimal(123_450, 3), sb_decimal(25, 2), 1_700_000_000, 3, 2);
+        let price = parse_switchboard_price(&data).unwrap();
+        assert_eq!(price.expo, -16);
+        assert_eq!(price.scaled_to(-3), Some(123_450));
+        assert_eq!(price.conf, 25 * 10u64.pow(14));
+        assert_eq!(price.timestamp, 1_700_000_000);
+    }
+
+    #[test]
+    fn keeps_full_precision_for_small_switchboard_values() {
+        let data = fake_switchboard_account(sb_decimal(15, 1), 0, 0, 1, 1);
+        let price = parse_switchboard_price(&data).unwrap();
+        assert_eq!(price, OraclePrice { value: 15 * 10i64.pow(17), expo: -18, conf: 0, timestamp: 0 });
+        assert_eq!(price.scaled_to(-2), Some(150));
+    }
+
+    #[test]
+    fn rejects_switchboard_without_samples_or_discriminator() {
+        let data = fake_switchboard_account(1, 0, 0, 1, 2);
+        assert!(parse_switchboard_price(&data).is_err());
+        let data = fake_switchboard_account(1, 0, 0, 0, 0);
+        assert!(parse_switchboard_price(&data).is_err());
+
+        let mut data = fake_switchboard_account(1, 0, 0, 1, 1);
+        data[0] = 0;
+        assert!(parse_switchboard_price(&data).is_err());
+        assert!(parse_switchboard_price(&data[..SWITCHBOARD_PULL_FEED_LEN - 1]).is_err());
+    }
+
+    /// `PullFeedAccountData` and its members as declared in switchboard-on-demand 0.3.8
+    /// (`src/on_demand/accounts/pull_feed.rs`), used to check our offsets independently.
+    mod upstream {
+        #[repr(C)]
+        pub struct OracleSubmission {
+            pub oracle: [u8; 32],
+            pub slot: u64,
+            pub landed_at: u64,
+            pub value: i128,
+        }
+
+        #[repr(C)]
+        pub struct CurrentResult {
+            pub value: i128,
+            pub std_dev: i128,
+            pub mean: i128,
+            pub range: i128,
+            pub min_value: i128,
+            pub max_value: i128,
+            pub num_samples: u8,
+            pub submission_idx: u8,
+            pub padding1: [u8; 6],
+            pub slot: u64,
+            pub min_slot: u64,
+            pub max_slot: u64,
+        }
+
+        #[repr(C)]
+        pub struct CompactResult {
+            pub std_dev: f32,
+            pub mean: f32,
+            pub slot: u64,
+        }
+
+        #[repr(C)]
+        pub struct PullFeedAccountData {
+            pub submissions: [OracleSubmission; 32],
+            pub authority: [u8; 32],
+            pub queue: [u8; 32],
+            pub feed_hash: [u8; 32],
+            pub initialized_at: i64,
+            pub permissions: u64,
+            pub max_variance: u64,
+            pub min_responses: u32,
+            pub name: [u8; 32],
+            pub padding1: [u8; 2],
+            pub historical_result_idx: u8,
+            pub min_sample_size: u8,
+            pub last_update_timestamp: i64,
+            pub lut_slot: u64,
+            pub reserved1: [u8; 32],
+            pub result: CurrentResult,
+            pub max_staleness: u32,
+            pub padding2: [u8; 12],
+            pub historical_results: [CompactResult; 32],
+            pub ebuf4: [u8; 8],
+            pub ebuf3: [u8; 24],
+            pub submission_timestamps: [i64; 32],
+        }
+    }
+
+    #[test]
+    fn switchboard_offsets_match_upstream_layout() {
+        use std::mem::{offset_of, size_of};
+        use upstream::{CurrentResult, PullFeedAccountData};
+
+        let result = 8 + offset_of!(PullFeedAccountData, result);
+        assert_eq!(SB_MIN_SAMPLE_SIZE_OFFSET, 8 + offset_of!(PullFeedAccountData, min_sample_size));
+        assert_eq!(SB_LAST_UPDATE_TIMESTAMP_OFFSET, 8 + offset_of!(PullFeedAccountData, last_update_timestamp));
+        assert_eq!(SB_RESULT_VALUE_OFFSET, result + offset_of!(CurrentResult, value));
+        assert_eq!(SB_RESULT_STD_DEV_OFFSET, result + offset_of!(CurrentResult, std_dev));
+        assert_eq!(SB_RESULT_NUM_SAMPLES_OFFSET, result + offset_of!(CurrentResult, num_samples));
+        assert_eq!(SWITCHBOARD_PULL_FEED_LEN, 8 + size_of::<PullFeedAccountData>());
+    }
+
+    #[test]
+    fn parses_feed_laid_out_by_upstream_struct() {
+        use std::mem::{offset_of, size_of};
+        use upstream::{CurrentResult, PullFeedAccountData};
+
+        // 64_123.5 +/- 12.25 at 2024-01-01T00:00:00Z, 5 of at least 3 samples
+        let mut data = vec![0u8; 8 + size_of::<PullFeedAccountData>()];
+        data[..8].copy_from_slice(&SWITCHBOARD_PULL_FEED_DISCRIMINATOR);
+        let at = |field: usize| 8 + field;
+        let result = offset_of!(PullFeedAccountData, result);
+        data[at(offset_of!(PullFeedAccountData, min_sample_size))] = 3;
+        let ts = at(offset_of!(PullFeedAccountData, last_update_timestamp));
+        data[ts..ts + 8].copy_from_slice(&1_704_067_200i64.to_le_bytes());
+        let value = at(result + offset_of!(CurrentResult, value));
+        data[value..value + 16].copy_from_slice(&64_123_500_000_000_000_000_000i128.to_le_bytes());
+        let std_dev = at(result + offset_of!(CurrentResult, std_dev));
+        data[std_dev..std_dev + 16].copy_from_slice(&12_250_000_000_000_000_000i128.to_le_bytes());
+        data[at(result + offset_of!(CurrentResult, num_samples))] = 5;
+
+        let price = parse_switchboard_price(&data).unwrap();
+        assert_eq!(price.scaled_to(-1), Some(641_235));
+        assert_eq!(price.value as i128 * 10i128.pow((18 + price.expo) as u32), 64_123_500_000_000_000_000_000);
+        assert_eq!(price.conf as i128 * 10i128.pow((18 + price.expo) as u32), 12_250_000_000_000_000_000);
+        assert_eq!(price.timestamp, 1_704_067_200);
+    }
+
+    fn price(value: i64, expo: i32, conf: u64, timestamp: i64) -> OraclePrice {
+        OraclePrice { value, expo, conf, timestamp }
+    }
+
+    #[test]
+    fn price_age_is_measured_from_reference() {
+        assert!(check_price_age(&price(1, 0, 0, 940), 1_000, 60).is_ok());
+        assert!(check_price_age(&price(1, 0, 0, 1_050), 1_000, 60).is_ok());
+        assert!(check_price_age(&price(1, 0, 0, 939), 1_000, 60).is_err());
+        assert!(check_price_age(&price(1, 0, 0, 1_060), 1_000, 60).is_ok());
+        // Published after close: the live price at resolution time is not accepted
+        assert!(check_price_age(&price(1, 0, 0, 1_061), 1_000, 60).is_err());
+        assert!(check_price_age(&price(1, 0, 0, i64::MAX), 1_000, 60).is_err());
+    }
+
+    #[test]
+    fn confidence_is_bounded_by_bps_of_price() {
+        // 2% interval on a price of 10_000
+        assert!(check_confidence(&price(10_000, -2, 200, 0), 200).is_ok());
+        assert!(check_confidence(&price(10_000, -2, 201, 0), 200).is_err());
+        assert!(check_confidence(&price(-10_000, -2, 100, 0), 200).is_ok());
+    }
+
+    #[test]
+    fn deviation_compares_feeds_at_common_exponent() {
+        // 100.00 vs 100.9 (1% threshold)
+        let primary = price(10_000, -2, 0, 0);
+        assert!(check_deviation(&primary, &price(1_009, -1, 0, 0), 100).is_ok());
+        assert!(check_deviation(&primary, &price(1_011, -1, 0, 0), 100).is_err());
+    }
+
+    #[test]
+    fn scales_between_exponents() {
+        let price = OraclePrice { value: 12_345_678, expo: -8, conf: 0, timestamp: 0 };
+        assert_eq!(price.scaled_to(-8), Some(12_345_678));
+        assert_eq!(price.scaled_to(-6), Some(123_456));
+        assert_eq!(price.scaled_to(-10), Some(1_234_567_800));
+        assert_eq!(price.scaled_to(-30), None);
+    }
+}
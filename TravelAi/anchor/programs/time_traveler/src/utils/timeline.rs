@@ -0,0 +1,382 @@
+// Timeline archive CPI helpers
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::errors::TimeTravelerError;
+use crate::events::TimelineMirrorSkipped;
+use crate::state::{Pool, PoolKind, PoolStatus, Registry};
+use crate::utils::math::{mul_div, Rounding, BPS_DENOMINATOR};
+
+/// Anchor discriminator of the timeline `update_prediction_stats` instruction
+/// (first 8 bytes of sha256("global:update_prediction_stats")).
+pub const UPDATE_PREDICTION_STATS_DISCRIMINATOR: [u8; 8] = [202, 220, 65, 118, 220, 171, 76, 232];
+/// Anchor discriminator of the timeline `resolve_prediction` instruction
+/// (first 8 bytes of sha256("global:resolve_prediction")).
+pub const RESOLVE_PREDICTION_DISCRIMINATOR: [u8; 8] = [199, 159, 54, 235, 121, 68, 53, 137];
+
+/// Anchor discriminator of the timeline `ArchiveWriter` account
+/// (first 8 bytes of sha256("account:ArchiveWriter")).
+pub const ARCHIVE_WRITER_DISCRIMINATOR: [u8; 8] = [142, 157, 190, 68, 28, 7, 126, 244];
+/// Anchor discriminator of the timeline `PredictionRecord` account
+/// (first 8 bytes of sha256("account:PredictionRecord")).
+pub const PREDICTION_RECORD_DISCRIMINATOR: [u8; 8] = [75, 202, 55, 23, 136, 28, 7, 241];
+
+/// Mirror of the timeline program's `Outcome`; variant order must match.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TimelineOutcome {
+    Pending,
+    Win,
+    Loss,
+    Neutral,
+}
+
+/// Archive PDA of the timeline program: ["archive"]
+pub fn timeline_archive_address(timeline_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"archive"], timeline_program).0
+}
+
+/// ArchiveWriter PDA of the timeline program: ["writer", archive]
+pub fn timeline_writer_address(timeline_program: &Pubkey, archive: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"writer", archive.as_ref()], timeline_program).0
+}
+
+/// PredictionRecord PDA of the timeline program: ["prediction", archive, prediction_id_le]
+pub fn timeline_prediction_address(
+    timeline_program: &Pubkey,
+    archive: &Pubkey,
+    prediction_id: u64,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"prediction", archive.as_ref(), &prediction_id.to_le_bytes()],
+        timeline_program,
+    )
+    .0
+}
+
+/// Whether ArchiveWriter data names `writer` as the archive writer.
+pub fn timeline_writer_is(data: &[u8], writer: &Pubkey) -> bool {
+    // discriminator, archive, writer
+    data.len() >= 8 + 32 + 32
+        && data[..8] == ARCHIVE_WRITER_DISCRIMINATOR
+        && data[40..72] == writer.to_bytes()
+}
+
+/// Whether PredictionRecord data is record `prediction_id` and still Pending.
+pub fn timeline_prediction_pending(data: &[u8], prediction_id: u64) -> bool {
+    fn skip(data: &[u8], offset: usize, len: usize) -> Option<usize> {
+        offset.checked_add(len).filter(|end| *end <= data.len())
+    }
+    fn skip_string(data: &[u8], offset: usize) -> Option<usize> {
+        let end = skip(data, offset, 4)?;
+        let len = u32::from_le_bytes(data[offset..end].try_into().ok()?);
+        skip(data, end, len as usize)
+    }
+
+    let read_outcome = || -> Option<(u64, u8)> {
+        if data.get(..8)? != PREDICTION_RECORD_DISCRIMINATOR {
+            return None;
+        }
+        let id = u64::from_le_bytes(data.get(40..48)?.try_into().ok()?);
+        let offset = skip_string(data, 48)?; // ai_model_version
+        let offset = skip(data, offset, 8)?; // timestamp
+        let offset = skip_string(data, offset)?; // signal
+        let offset = skip(data, offset, 2)?; // confidence
+        let offset = skip_string(data, offset)?; // volatility_tier
+        let offset = skip(data, offset, 8 + 8)?; // total_pool_tokens, followers
+        Some((id, *data.get(offset)?))
+    };
+    read_outcome() == Some((prediction_id, TimelineOutcome::Pending as u8))
+}
+
+/// Invoke a timeline instruction taking (archive, prediction, authority,
+/// archive_writer), with `writer` signing through `signer_seeds`.
+fn invoke_timeline<'info>(
+    timeline_program: AccountInfo<'info>,
+    archive: AccountInfo<'info>,
+    prediction: AccountInfo<'info>,
+    writer: AccountInfo<'info>,
+    archive_writer: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    data: Vec<u8>,
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: timeline_program.key(),
+        accounts: vec![
+            AccountMeta::new_readonly(archive.key(), false),
+            AccountMeta::new(prediction.key(), false),
+            AccountMeta::new_readonly(writer.key(), true),
+            AccountMeta::new_readonly(archive_writer.key(), false),
+        ],
+        data,
+    };
+    invoke_signed(
+        &ix,
+        &[archive, prediction, writer, archive_writer, timeline_program],
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+/// CPI into timeline `update_prediction_stats(total_pool_tokens, followers)`.
+#[allow(clippy::too_many_arguments)]
+pub fn update_prediction_stats<'info>(
+    timeline_program: AccountInfo<'info>,
+    archive: AccountInfo<'info>,
+    prediction: AccountInfo<'info>,
+    writer: AccountInfo<'info>,
+    archive_writer: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    total_pool_tokens: u64,
+    followers: u64,
+) -> Result<()> {
+    let mut data = UPDATE_PREDICTION_STATS_DISCRIMINATOR.to_vec();
+    (total_pool_tokens, followers).serialize(&mut data)?;
+    invoke_timeline(timeline_program, archive, prediction, writer, archive_writer, signer_seeds, data)
+}
+
+/// CPI into timeline `resolve_prediction(outcome, payout_ratio_bps)`.
+#[allow(clippy::too_many_arguments)]
+pub fn resolve_prediction<'info>(
+    timeline_program: AccountInfo<'info>,
+    archive: AccountInfo<'info>,
+    prediction: AccountInfo<'info>,
+    writer: AccountInfo<'info>,
+    archive_writer: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    outcome: TimelineOutcome,
+    payout_ratio_bps: u16,
+) -> Result<()> {
+    let mut data = RESOLVE_PREDICTION_DISCRIMINATOR.to_vec();
+    (outcome, payout_ratio_bps).serialize(&mut data)?;
+    invoke_timeline(timeline_program, archive, prediction, writer, archive_writer, signer_seeds, data)
+}
+
+/// Outcome and payout ratio recorded in the timeline for a closed pool.
+/// Binary categorical pools report Win when outcome 0 (YES) wins and Loss
+/// otherwise; range, scalar and refunded pools report Neutral. The payout
+/// ratio is the gross return per unit staked on the winning bucket, i.e.
+/// `total_contributed / winning_total` in basis points (10_000 = stake back),
+/// capped at `u16::MAX`. It is 0 when no bucket won, including scalar pools.
+pub fn timeline_result(pool: &Pool) -> Result<(TimelineOutcome, u16)> {
+    let outcome = match (pool.status, pool.kind, pool.winning_outcome) {
+        (PoolStatus::Resolved, PoolKind::Categorical, Some(0)) if pool.num_outcomes == 2 => {
+            TimelineOutcome::Win
+        }
+        (PoolStatus::Resolved, PoolKind::Categorical, Some(_)) if pool.num_outcomes == 2 => {
+            TimelineOutcome::Loss
+        }
+        _ => TimelineOutcome::Neutral,
+    };
+    let winning_total = pool
+        .winning_outcome
+        .filter(|_| pool.status == PoolStatus::Resolved)
+        .and_then(|index| pool.outcome_total(index));
+    let payout_ratio_bps = match winning_total {
+        Some(winning_total) if winning_total > 0 => {
+            let ratio = mul_div(
+                pool.total_contributed as u128,
+                BPS_DENOMINATOR as u128,
+                winning_total as u128,
+                Rounding::Down,
+            )
+            .ok_or(TimeTravelerError::NumericalOverflow)?;
+            ratio.min(u16::MAX as u128) as u16
+        }
+        _ => 0,
+    };
+    Ok((outcome, payout_ratio_bps))
+}
+
+/// Mirror a just-closed pool into its linked timeline PredictionRecord:
+/// update the record's stats and resolve it in the registry's timeline
+/// program, signed by the registry PDA as the archive's recorded writer, with
+/// the result from `timeline_result`.
+/// Does nothing for unlinked pools or when no timeline program is configured.
+/// The accounts must be the configured ones, but a record that is already
+/// resolved, does not exist, or does not list the registry PDA as writer is
+/// skipped with a `TimelineMirrorSkipped` event so it cannot block resolution;
+/// clearing `registry.timeline_program` disables the mirror altogether.
+pub fn mirror_pool_result<'info>(
+    pool: &Account<'info, Pool>,
+    registry: &Account<'info, Registry>,
+    timeline_program: Option<&UncheckedAccount<'info>>,
+    archive: Option<&UncheckedAccount<'info>>,
+    archive_writer: Option<&UncheckedAccount<'info>>,
+    prediction: Option<&UncheckedAccount<'info>>,
+) -> Result<()> {
+    let timeline_program_id = registry.timeline_program;
+    let Some(prediction_id) = pool
+        .timeline_prediction_id
+        .filter(|_| timeline_program_id != Pubkey::default())
+    else {
+        return Ok(());
+    };
+
+    let timeline_program = timeline_program.ok_or(TimeTravelerError::TimelineAccountsMissing)?;
+    let archive = archive.ok_or(TimeTravelerError::TimelineAccountsMissing)?;
+    let archive_writer = archive_writer.ok_or(TimeTravelerError::TimelineAccountsMissing)?;
+    let prediction = prediction.ok_or(TimeTravelerError::TimelineAccountsMissing)?;
+    require!(
+        archive.key() == timeline_archive_address(&timeline_program_id)
+            && archive_writer.key() == timeline_writer_address(&timeline_program_id, &archive.key())
+            && prediction.key()
+                == timeline_prediction_address(&timeline_program_id, &archive.key(), prediction_id),
+        TimeTravelerError::InvalidTimelineAccount
+    );
+
+    let writable = archive_writer.owner == &timeline_program_id
+        && prediction.owner == &timeline_program_id
+        && timeline_writer_is(&archive_writer.try_borrow_data()?, &registry.key())
+        && timeline_prediction_pending(&prediction.try_borrow_data()?, prediction_id);
+    if !writable {
+        emit!(TimelineMirrorSkipped {
+            pool: pool.key(),
+            prediction_id,
+        });
+        return Ok(());
+    }
+
+    let (outcome, payout_ratio_bps) = timeline_result(pool)?;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[b"registry", &[registry.bump]]];
+    update_prediction_stats(
+        timeline_program.to_account_info(),
+        archive.to_account_info(),
+        prediction.to_account_info(),
+        registry.to_account_info(),
+        archive_writer.to_account_info(),
+        signer_seeds,
+        pool.total_contributed,
+        pool.contributors,
+    )?;
+    resolve_prediction(
+        timeline_program.to_account_info(),
+        archive.to_account_info(),
+        prediction.to_account_info(),
+        registry.to_account_info(),
+        archive_writer.to_account_info(),
+        signer_seeds,
+        outcome,
+        payout_ratio_bps,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::hash::hash;
+    use crate::state::tests::pool;
+    use crate::state::Resolution;
+
+    fn sighash(namespace: &str, name: &str) -> [u8; 8] {
+        hash(format!("{namespace}:{name}").as_bytes()).to_bytes()[..8]
+            .try_into()
+            .unwrap()
+    }
+
+    /// PredictionRecord data as the timeline program serializes it.
+    fn prediction_record(prediction_id: u64, outcome: TimelineOutcome) -> Vec<u8> {
+        let mut data = PREDICTION_RECORD_DISCRIMINATOR.to_vec();
+        (
+            Pubkey::new_unique(),
+            prediction_id,
+            "model-v2".to_string(),
+            1_700_000_000i64,
+            "SOL up".to_string(),
+            80u16,
+            "green".to_string(),
+            1_000u64,
+            7u64,
+            outcome,
+            0u16,
+            0i64,
+            "bafy".to_string(),
+            255u8,
+        )
+            .serialize(&mut data)
+            .unwrap();
+        data
+    }
+
+    #[test]
+    fn discriminators_match_anchor_sighash() {
+        assert_eq!(UPDATE_PREDICTION_STATS_DISCRIMINATOR, sighash("global", "update_prediction_stats"));
+        assert_eq!(RESOLVE_PREDICTION_DISCRIMINATOR, sighash("global", "resolve_prediction"));
+        assert_eq!(ARCHIVE_WRITER_DISCRIMINATOR, sighash("account", "ArchiveWriter"));
+        assert_eq!(PREDICTION_RECORD_DISCRIMINATOR, sighash("account", "PredictionRecord"));
+    }
+
+    #[test]
+    fn outcome_serializes_as_variant_index() {
+        let mut data = Vec::new();
+        (TimelineOutcome::Loss, 2_500u16).serialize(&mut data).unwrap();
+        assert_eq!(data, vec![2, 0xc4, 0x09]);
+    }
+
+    #[test]
+    fn only_pending_records_with_the_linked_id_are_writable() {
+        assert!(timeline_prediction_pending(&prediction_record(42, TimelineOutcome::Pending), 42));
+        assert!(!timeline_prediction_pending(&prediction_record(42, TimelineOutcome::Win), 42));
+        assert!(!timeline_prediction_pending(&prediction_record(41, TimelineOutcome::Pending), 42));
+
+        let mut truncated = prediction_record(42, TimelineOutcome::Pending);
+        truncated.truncate(60);
+        assert!(!timeline_prediction_pending(&truncated, 42));
+        assert!(!timeline_prediction_pending(&[], 42));
+    }
+
+    fn resolved(kind: PoolKind, totals: &[u64], resolution: Resolution) -> Pool {
+        let mut pool = pool(kind, totals);
+        pool.apply_resolution(resolution).unwrap();
+        pool.mark_resolved().unwrap();
+        pool
+    }
+
+    #[test]
+    fn binary_pools_report_win_or_loss_with_the_winners_return() {
+        // 1_000 shared by the 400 on YES: 2.5x
+        let yes = resolved(PoolKind::Categorical, &[400, 600], Resolution::Outcome(0));
+        assert_eq!(timeline_result(&yes).unwrap(), (TimelineOutcome::Win, 25_000));
+        let no = resolved(PoolKind::Categorical, &[400, 600], Resolution::Outcome(1));
+        assert_eq!(timeline_result(&no).unwrap(), (TimelineOutcome::Loss, 16_666));
+        // Nobody lost: winners get their stake back
+        let sweep = resolved(PoolKind::Categorical, &[500, 0], Resolution::Outcome(0));
+        assert_eq!(timeline_result(&sweep).unwrap(), (TimelineOutcome::Win, 10_000));
+    }
+
+    #[test]
+    fn payout_ratio_is_capped_and_zero_without_winners() {
+        let long_shot = resolved(PoolKind::Categorical, &[1, 999_999], Resolution::Outcome(0));
+        assert_eq!(timeline_result(&long_shot).unwrap(), (TimelineOutcome::Win, u16::MAX));
+
+        // No stake on the winner refunds everyone
+        let refunded = resolved(PoolKind::Categorical, &[0, 600], Resolution::Outcome(0));
+        assert_eq!(refunded.status, PoolStatus::Cancelled);
+        assert_eq!(timeline_result(&refunded).unwrap(), (TimelineOutcome::Neutral, 0));
+
+        let scalar = resolved(PoolKind::Scalar { lower: 0, upper: 10 }, &[5, 5], Resolution::Value(4));
+        assert_eq!(timeline_result(&scalar).unwrap(), (TimelineOutcome::Neutral, 0));
+    }
+
+    #[test]
+    fn range_pools_are_neutral_but_report_the_bucket_return() {
+        let range = resolved(
+            PoolKind::Range { lower: 0, upper: 30 },
+            &[100, 200, 300],
+            Resolution::Value(15),
+        );
+        assert_eq!(timeline_result(&range).unwrap(), (TimelineOutcome::Neutral, 30_000));
+    }
+
+    #[test]
+    fn writer_record_must_name_the_registry() {
+        let registry = Pubkey::new_unique();
+        let mut data = ARCHIVE_WRITER_DISCRIMINATOR.to_vec();
+        (Pubkey::new_unique(), registry, 255u8).serialize(&mut data).unwrap();
+        assert!(timeline_writer_is(&data, &registry));
+        assert!(!timeline_writer_is(&data, &Pubkey::new_unique()));
+        data[0] ^= 1;
+        assert!(!timeline_writer_is(&data, &registry));
+    }
+}
@@ -0,0 +1,281 @@
+// End-to-end run of `verify_and_close_pool` against a fake Pyth price account.
+//
+// The program entrypoint is driven directly over a small in-memory bank of
+// accounts, with the clock sysvar served by syscall stubs.
+use std::collections::HashMap;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::{bpf_loader, program_stubs, system_program};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+
+use time_traveler::errors::TimeTravelerError;
+use time_traveler::state::*;
+use time_traveler::utils::{PYTH_ACCOUNT_TYPE_PRICE, PYTH_MAGIC, PYTH_STATUS_TRADING, PYTH_VERSION};
+
+const CLOSE_TS: i64 = 1_000;
+const NOW: i64 = CLOSE_TS + 100;
+
+/// Serves a fixed clock to `Clock::get()`.
+struct ClockStubs;
+
+impl program_stubs::SyscallStubs for ClockStubs {
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            unix_timestamp: NOW,
+            ..Clock::default()
+        };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        0
+    }
+}
+
+/// An account as stored in the test bank.
+struct BankAccount {
+    lamports: u64,
+    data: Vec<u8>,
+    owner: Pubkey,
+    executable: bool,
+}
+
+impl BankAccount {
+    fn new(data: Vec<u8>, owner: Pubkey) -> Self {
+        Self {
+            lamports: 1_000_000_000,
+            data,
+            owner,
+            executable: false,
+        }
+    }
+
+    fn program() -> Self {
+        Self {
+            lamports: 1,
+            data: Vec::new(),
+            owner: bpf_loader::id(),
+            executable: true,
+        }
+    }
+}
+
+fn error_code(error: TimeTravelerError) -> ProgramError {
+    ProgramError::Custom(error.into())
+}
+
+fn anchor_data<T: AccountSerialize>(account: &T, space: usize) -> Vec<u8> {
+    let mut data = Vec::with_capacity(8 + space);
+    account.try_serialize(&mut data).unwrap();
+    data.resize(8 + space, 0);
+    data
+}
+
+/// Fake Pyth v2 price account (price * 10^expo), trading.
+fn fake_pyth_account(price: i64, expo: i32, conf: u64, publish_time: i64) -> Vec<u8> {
+    let mut data = vec![0u8; 3312];
+    data[0..4].copy_from_slice(&PYTH_MAGIC.to_le_bytes());
+    data[4..8].copy_from_slice(&PYTH_VERSION.to_le_bytes());
+    data[8..12].copy_from_slice(&PYTH_ACCOUNT_TYPE_PRICE.to_le_bytes());
+    data[20..24].copy_from_slice(&expo.to_le_bytes());
+    data[96..104].copy_from_slice(&publish_time.to_le_bytes());
+    data[208..216].copy_from_slice(&price.to_le_bytes());
+    data[216..224].copy_from_slice(&conf.to_le_bytes());
+    data[224..228].copy_from_slice(&PYTH_STATUS_TRADING.to_le_bytes());
+    data
+}
+
+fn registry(authority: Pubkey, oracle: Pubkey) -> Registry {
+    Registry {
+        authority,
+        pending_authority: Pubkey::default(),
+        version: 1,
+        fee_bps: 0,
+        oracle,
+        oracle_type: OracleType::Pyth,
+        secondary_oracle: Pubkey::default(),
+        secondary_oracle_type: OracleType::Pyth,
+        max_price_age_secs: 60,
+        max_conf_bps: 200,
+        max_deviation_bps: 100,
+        referral_bps: 0,
+        dispute_window_secs: 86_400,
+        dispute_bond: 0,
+        arbiter: Pubkey::default(),
+        arbitration_window_secs: 0,
+        early_exit_penalty_bps: 0,
+        exit_lockout_secs: 0,
+        default_limits: ContributionLimits::default(),
+        default_lock_period_secs: 0,
+        community_bond: 0,
+        creator_fee_bps: 0,
+        timeline_program: Pubkey::default(),
+        close_grace_secs: 0,
+        paused: false,
+        config_version: 0,
+        bump: 255,
+    }
+}
+
+/// Binary YES/NO pool resolving YES when the price is at or above `target` (expo -2).
+fn oracle_pool(registry: Pubkey, authority: Pubkey, mint: Pubkey, target: i64) -> Pool {
+    let mut buckets = [OutcomeBucket::default(); MAX_OUTCOMES];
+    buckets[0].total = 300;
+    buckets[1].total = 700;
+    Pool {
+        registry,
+        authority,
+        mint,
+        native_sol: false,
+        signal_id: [7; 32],
+        status: PoolStatus::Locked,
+        kind: PoolKind::Categorical,
+        resolution_source: ResolutionSource::Oracle { expo: -2, target },
+        winning_outcome: None,
+        resolved_value: None,
+        open_ts: 0,
+        close_ts: CLOSE_TS,
+        lock_period_secs: 0,
+        dispute_deadline: 0,
+        disputer: Pubkey::default(),
+        dispute_bond: 0,
+        total_contributed: 1_000,
+        unsettled_stake: 0,
+        contributors: 2,
+        open_contributions: 2,
+        num_outcomes: 2,
+        paused: false,
+        limits: ContributionLimits::default(),
+        community: false,
+        creation_bond: 0,
+        creator_fee_bps: 0,
+        creator_fees: 0,
+        invalid: false,
+        timeline_prediction_id: None,
+        buckets,
+        bump: 255,
+    }
+}
+
+/// Write a registry, an oracle pool with its vault and a Pyth feed into a
+/// bank, run `verify_and_close_pool` and return the stored pool afterwards.
+fn run_verify(pyth_data: Vec<u8>, target: i64) -> std::result::Result<Pool, ProgramError> {
+    program_stubs::set_syscall_stubs(Box::new(ClockStubs));
+
+    let program_id = time_traveler::ID;
+    let authority = Pubkey::new_unique();
+    let oracle = Pubkey::new_unique();
+    let registry_key = Pubkey::new_unique();
+    let pool_key = Pubkey::new_unique();
+    let vault_key = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let mut vault_data = vec![0u8; spl_token::state::Account::LEN];
+    spl_token::state::Account {
+        mint,
+        owner: pool_key,
+        amount: 1_000,
+        state: spl_token::state::AccountState::Initialized,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut vault_data);
+
+    let mut bank: HashMap<Pubkey, BankAccount> = HashMap::new();
+    bank.insert(authority, BankAccount::new(Vec::new(), system_program::ID));
+    bank.insert(
+        registry_key,
+        BankAccount::new(anchor_data(&registry(authority, oracle), Registry::SIZE), program_id),
+    );
+    bank.insert(
+        pool_key,
+        BankAccount::new(
+            anchor_data(&oracle_pool(registry_key, authority, mint, target), Pool::SIZE),
+            program_id,
+        ),
+    );
+    bank.insert(vault_key, BankAccount::new(vault_data, spl_token::ID));
+    bank.insert(oracle, BankAccount::new(pyth_data, Pubkey::new_unique()));
+    bank.insert(spl_token::ID, BankAccount::program());
+    bank.insert(program_id, BankAccount::program());
+
+    let metas = time_traveler::accounts::VerifyAndClosePool {
+        authority,
+        registry: registry_key,
+        roles: None,
+        pool: pool_key,
+        pool_vault: vault_key,
+        oracle_feed: Some(oracle),
+        secondary_oracle_feed: None,
+        timeline_program: None,
+        timeline_archive: None,
+        timeline_writer: None,
+        timeline_prediction: None,
+        token_program: spl_token::ID,
+    }
+    .to_account_metas(None);
+    let data = time_traveler::instruction::VerifyAndClosePool {
+        resolution: Resolution::Oracle,
+    }
+    .data();
+
+    // One AccountInfo per key, shared by every position it appears in
+    let mut infos: HashMap<Pubkey, AccountInfo<'static>> = HashMap::new();
+    for meta in &metas {
+        if infos.contains_key(&meta.pubkey) {
+            continue;
+        }
+        let account: &'static mut BankAccount = Box::leak(Box::new(bank.remove(&meta.pubkey).unwrap()));
+        let key: &'static Pubkey = Box::leak(Box::new(meta.pubkey));
+        let is_signer = metas.iter().any(|m| m.pubkey == meta.pubkey && m.is_signer);
+        let is_writable = metas.iter().any(|m| m.pubkey == meta.pubkey && m.is_writable);
+        infos.insert(
+            meta.pubkey,
+            AccountInfo::new(
+                key,
+                is_signer,
+                is_writable,
+                &mut account.lamports,
+                &mut account.data,
+                &account.owner,
+                account.executable,
+                0,
+            ),
+        );
+    }
+    let accounts: Vec<AccountInfo<'static>> = metas.iter().map(|meta| infos[&meta.pubkey].clone()).collect();
+    let accounts: &'static [AccountInfo<'static>] = Box::leak(accounts.into_boxed_slice());
+
+    time_traveler::entry(&program_id, accounts, &data)?;
+
+    let pool_data = infos[&pool_key].try_borrow_data().unwrap();
+    Ok(Pool::try_deserialize(&mut &pool_data[..]).unwrap())
+}
+
+#[test]
+fn pyth_price_above_target_resolves_yes() {
+    // $152.34 published 5s before close vs a $150.00 target
+    let pool = run_verify(fake_pyth_account(15_234_000, -5, 1_000, CLOSE_TS - 5), 15_000).unwrap();
+    assert_eq!(pool.winning_outcome, Some(0));
+    assert_eq!(pool.status, PoolStatus::Resolved);
+    assert_eq!(pool.unsettled_stake, 300);
+}
+
+#[test]
+fn pyth_price_below_target_resolves_no() {
+    let pool = run_verify(fake_pyth_account(14_999_000, -5, 1_000, CLOSE_TS - 5), 15_000).unwrap();
+    assert_eq!(pool.winning_outcome, Some(1));
+    assert_eq!(pool.unsettled_stake, 700);
+}
+
+#[test]
+fn stale_pyth_price_is_rejected() {
+    let result = run_verify(fake_pyth_account(15_234_000, -5, 1_000, CLOSE_TS - 61), 15_000);
+    assert_eq!(result.err(), Some(error_code(TimeTravelerError::StaleOraclePrice)));
+}
+
+#[test]
+fn pyth_price_published_after_close_is_rejected() {
+    // Resolver waits 90s past close for a price that suits them
+    let result = run_verify(fake_pyth_account(15_234_000, -5, 1_000, CLOSE_TS + 90), 15_000);
+    assert_eq!(result.err(), Some(error_code(TimeTravelerError::OraclePriceTooLate)));
+}
@@ -1 +0,0 @@
-// Math helpers
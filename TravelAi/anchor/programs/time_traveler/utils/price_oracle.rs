@@ -1 +0,0 @@
-// Pyth/Chainlink helpers
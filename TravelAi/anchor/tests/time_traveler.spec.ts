@@ -43,6 +43,31 @@ describe("time_traveler program", () => {
     // Use anchor’s built-in Mint helper or airdrop
     mint = await createMint(provider); // helper defined below
 
+    // Pools need the registry treasury for their mint
+    const [treasury] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury"), registryPda.toBuffer(), mint.toBuffer()],
+      program.programId
+    );
+    const [treasuryStats] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury_stats"), registryPda.toBuffer(), mint.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initTreasury()
+      .accounts({
+        authority: provider.wallet.publicKey,
+        registry: registryPda,
+        roles: null,
+        mint,
+        treasury,
+        treasuryStats,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+
     [poolPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("pool"), Buffer.from(signalId)],
       program.programId
@@ -53,17 +78,42 @@ describe("time_traveler program", () => {
       program.programId
     );
 
+    const [metadataPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), poolPda.toBuffer()],
+      program.programId
+    );
+
     const now = Math.floor(Date.now() / 1000);
     const openTs = now;
-    const closeTs = now + 60; // closes in 1 minute
+    const closeTs = now + 600; // closes in 10 minutes, locks 5 minutes before
 
     await program.methods
-      .createPool([...signalId], new anchor.BN(openTs), new anchor.BN(closeTs))
+      .createPool([...signalId], {
+        openTs: new anchor.BN(openTs),
+        closeTs: new anchor.BN(closeTs),
+        outcomeLabels: [new Array(32).fill(1), new Array(32).fill(2)], // YES / NO label hashes
+        kind: { categorical: {} },
+        resolutionSource: { authority: {} },
+        limits: null, // registry default contribution limits
+        lockPeriodSecs: null, // registry default lock period
+        nativeSol: false,
+        timelinePredictionId: null, // not mirrored to the timeline archive
+      }, {
+        question: "Will SOL close above $200 in 10 minutes?",
+        assetSymbol: "SOL",
+        assetMint: PublicKey.default,
+        volatilityTier: "yellow",
+        ipfsCid: "",
+        resolutionCriteria: "Authority proposes the SOL/USD price at close_ts",
+      })
       .accounts({
         authority: provider.wallet.publicKey,
         registry: registryPda,
+        roles: null, // registry authority holds every role
         pool: poolPda,
+        metadata: metadataPda,
         mint,
+        treasuryStats,
         poolVault,
         systemProgram: SystemProgram.programId,
         tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
@@ -97,7 +147,7 @@ describe("time_traveler program", () => {
     );
 
     await program.methods
-      .contribute(new anchor.BN(100))
+      .contribute(new anchor.BN(100), 0, null) // back YES, no referrer
       .accounts({
         contributor: contributor.publicKey,
         pool: poolPda,
@@ -105,6 +155,7 @@ describe("time_traveler program", () => {
         contribution: contribPda,
         userTokenAccount,
         poolVault,
+        referral: null,
         mint,
         systemProgram: SystemProgram.programId,
         tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,